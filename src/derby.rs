@@ -0,0 +1,136 @@
+//! Connection string generator for `Apache Derby` (JDBC)
+
+use std::fmt::Display;
+
+use crate::HostPort;
+
+/// The way the database is accessed
+#[derive(Debug)]
+enum Mode {
+    /// Network server mode (`jdbc:derby://<host>:<port>/<db_name>`)
+    Network {
+        host_port: HostPort,
+        db_name: String,
+    },
+    /// Embedded mode (`jdbc:derby:<path>`)
+    Embedded { path: String },
+}
+
+/// Struct representing an `Apache Derby` JDBC connection string
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct DerbyConnectionString {
+    mode: Mode,
+    create: bool,
+}
+
+impl DerbyConnectionString {
+    /// Creates a new [`DerbyConnectionString`] for a database served by the Derby network server
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::derby::DerbyConnectionString;
+    ///
+    /// let conn_string = DerbyConnectionString::network("localhost", 1527, "db_name");
+    ///
+    /// assert_eq!(conn_string.to_string(), "jdbc:derby://localhost:1527/db_name");
+    /// ```
+    #[must_use]
+    pub fn network(host: &str, port: usize, db_name: &str) -> Self {
+        Self {
+            mode: Mode::Network {
+                host_port: HostPort {
+                    host: host.to_string(),
+                    port,
+                },
+                db_name: db_name.to_string(),
+            },
+            create: false,
+        }
+    }
+
+    /// Creates a new [`DerbyConnectionString`] for an embedded database located at the given path
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::derby::DerbyConnectionString;
+    ///
+    /// let conn_string = DerbyConnectionString::embedded("/data/db_name");
+    ///
+    /// assert_eq!(conn_string.to_string(), "jdbc:derby:/data/db_name");
+    /// ```
+    #[must_use]
+    pub fn embedded(path: &str) -> Self {
+        Self {
+            mode: Mode::Embedded {
+                path: path.to_string(),
+            },
+            create: false,
+        }
+    }
+
+    /// Sets whether the database should be created if it doesn't exist yet
+    ///
+    /// Parameters: `create=true`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::derby::DerbyConnectionString;
+    ///
+    /// DerbyConnectionString::embedded("db_name").set_create(true);
+    /// ```
+    #[must_use]
+    pub fn set_create(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+}
+
+impl Display for DerbyConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.mode {
+            Mode::Network {
+                host_port: HostPort { host, port },
+                db_name,
+            } => write!(f, "jdbc:derby://{host}:{port}/{db_name}")?,
+            Mode::Embedded { path } => write!(f, "jdbc:derby:{path}")?,
+        }
+
+        if self.create {
+            write!(f, ";create=true")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DerbyConnectionString;
+
+    /// Test network server mode
+    #[test]
+    fn test_network() {
+        let conn_string = DerbyConnectionString::network("Host", 1527, "DbName");
+        assert_eq!(&conn_string.to_string(), "jdbc:derby://Host:1527/DbName");
+
+        let conn_string = conn_string.set_create(true);
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:derby://Host:1527/DbName;create=true"
+        );
+    }
+
+    /// Test embedded mode
+    #[test]
+    fn test_embedded() {
+        let conn_string = DerbyConnectionString::embedded("/data/DbName").set_create(true);
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:derby:/data/DbName;create=true"
+        );
+
+        let conn_string = conn_string.set_create(false);
+        assert_eq!(&conn_string.to_string(), "jdbc:derby:/data/DbName");
+    }
+}
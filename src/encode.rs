@@ -0,0 +1,435 @@
+//! Helpers for encoding values in URI based connection strings
+//!
+//! [`percent_encode`] is public so custom URI components can be encoded the same way as the
+//! components set via the builders. [`percent_decode`] reverses it.
+
+/// Percent-encodes every character which isn't unreserved
+/// (<https://www.rfc-editor.org/rfc/rfc3986#section-2.3>)
+///
+/// The characters `A-Z`, `a-z`, `0-9`, `-`, `.`, `_` and `~` are kept as they are.
+/// Every other byte is encoded as `%XX` (uppercase hex digits), this includes:
+/// - all reserved characters (`:/?#[]@!$&'()*+,;=`)
+/// - `%` and space
+/// - control characters and all remaining ASCII punctuation (e.g. `"`, `<`, `>`, `\`, `{`, `}`)
+/// - every byte of multibyte (UTF-8) characters, e.g. `ä` => `%C3%A4`
+///
+/// This is the encoding the builders use for usernames, passwords, hosts and database names.
+///
+/// # Examples
+/// ```rust
+/// use connection_string_generator::encode::percent_encode;
+///
+/// assert_eq!(percent_encode("p@ss:w/rd?"), "p%40ss%3Aw%2Frd%3F");
+/// assert_eq!(percent_encode("safe-._~"), "safe-._~");
+/// ```
+#[must_use]
+pub fn percent_encode(s: &str) -> String {
+    simple_percent_encode(s)
+}
+
+/// Decodes all `%XX` escapes, e.g. the ones created by [`percent_encode`]
+///
+/// `percent_decode(&percent_encode(s))` always returns `s`.
+/// Every `%` has to be followed by two hex digits (upper- or lowercase).
+/// Decoded byte sequences which aren't valid UTF-8 are replaced by `U+FFFD`.
+///
+/// # Errors
+/// Returns a [`DecodeError`] with the byte offset of the first malformed escape (e.g. `%zz` or a trailing `%`)
+///
+/// # Examples
+/// ```rust
+/// use connection_string_generator::encode::{percent_decode, percent_encode};
+///
+/// assert_eq!(percent_decode("p%40ss%3Aw%2Frd").unwrap(), "p@ss:w/rd");
+/// assert_eq!(percent_decode(&percent_encode("ä b")).unwrap(), "ä b");
+/// assert_eq!(percent_decode("100%").unwrap_err().offset(), 3);
+/// ```
+pub fn percent_decode(s: &str) -> Result<String, DecodeError> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = s
+                .get(i + 1..i + 3)
+                .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+                .ok_or(DecodeError { offset: i })?;
+
+            // Can't fail as both characters are hex digits
+            decoded.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+/// Error returned by [`percent_decode`] if a `%` isn't followed by two hex digits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    offset: usize,
+}
+
+impl DecodeError {
+    /// Returns the byte offset of the malformed escape (the position of the `%`)
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "malformed percent-encoding at byte {}: `%` must be followed by two hex digits",
+            self.offset
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Percent-encodes every character which isn't unreserved
+/// (<https://www.rfc-editor.org/rfc/rfc3986#section-2.3>: `A-Z a-z 0-9 - . _ ~`)
+///
+/// Multibyte characters are encoded byte by byte (UTF-8), e.g. `ä` => `%C3%A4`.
+/// `+` is always encoded (`%2B`) as some parsers interpret a literal `+` as a space.
+pub(crate) fn simple_percent_encode(s: &str) -> String {
+    use std::fmt::Write;
+
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+
+    encoded
+}
+
+/// Percent-encodes a value in the query string
+///
+/// Only the characters which would break the query string are encoded: `&`, `=`, `#`, `%`,
+/// `+` (form decoders would turn it into a space), space, control characters and every byte of
+/// multibyte (UTF-8) characters. Everything else (e.g. `,`, `!`, `;` and `:`) is kept as it is,
+/// so values like `require_auth=!password,!md5` stay readable.
+#[cfg(any(
+    feature = "postgres",
+    feature = "clickhouse",
+    feature = "mysql",
+    feature = "sqlite",
+    feature = "mongodb"
+))]
+pub(crate) fn simple_query_encode(s: &str) -> String {
+    use std::fmt::Write;
+
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        if byte.is_ascii_control()
+            || !byte.is_ascii()
+            || matches!(byte, b'&' | b'=' | b'#' | b'%' | b'+' | b' ')
+        {
+            let _ = write!(encoded, "%{byte:02X}");
+        } else {
+            encoded.push(char::from(byte));
+        }
+    }
+
+    encoded
+}
+
+/// Encodes the host for the connection string
+///
+/// IPv6 addresses are enclosed in brackets and the `%` of a zone identifier is encoded as `%25`
+/// (e.g. `fe80::1%eth0` => `[fe80::1%25eth0]`). All other hosts are percent-encoded.
+#[cfg(any(
+    feature = "postgres",
+    feature = "hive_metastore",
+    feature = "clickhouse",
+    feature = "mysql",
+    feature = "mongodb",
+    feature = "eventstoredb",
+    feature = "jdbc"
+))]
+pub(crate) fn encode_host(host: &str) -> String {
+    let (address, zone) = match strip_brackets(host).split_once('%') {
+        Some((address, zone)) => (address, Some(zone)),
+        None => (strip_brackets(host), None),
+    };
+
+    if address.parse::<std::net::Ipv6Addr>().is_err() {
+        return simple_percent_encode(host);
+    }
+
+    match zone {
+        Some(zone) => format!("[{address}%25{}]", simple_percent_encode(zone)),
+        None => format!("[{address}]"),
+    }
+}
+
+/// Removes the brackets around an IPv6 address (if present)
+#[cfg(any(
+    feature = "postgres",
+    feature = "hive_metastore",
+    feature = "clickhouse",
+    feature = "mysql",
+    feature = "mongodb",
+    feature = "eventstoredb",
+    feature = "jdbc"
+))]
+pub(crate) fn strip_brackets(host: &str) -> &str {
+    host.strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host)
+}
+
+/// Percent-encodes a file path
+///
+/// In contrast to [`simple_percent_encode`] the characters `/` and `:` are kept as they are,
+/// so the path (including Windows drive letters) stays readable.
+#[cfg(feature = "sqlite")]
+pub(crate) fn simple_path_encode(s: &str) -> String {
+    use std::fmt::Write;
+
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/' | b':') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+
+    encoded
+}
+
+/// Simple encoding for values in an ADO.NET style key/value connection string (`SQL Server`, `Npgsql`)
+///
+/// According to [Microsoft](https://learn.microsoft.com/en-us/sql/connect/ado-net/connection-strings?view=sql-server-ver16)
+/// (Accessed: 2024-08-20):
+/// > If a value contains the semicolon, Unicode control characters,
+/// > or leading or trailing white space, it must be enclosed in single or double quotation marks
+///
+/// > The enclosing character may not occur within the value it encloses.
+/// > Therefore, a value containing single quotation marks can be enclosed only in double quotation marks, and vice versa
+///
+/// > You can also escape the enclosing character by using two of them together
+///
+/// This function checks if quotation marks are needed and only adds them if they are required.
+///
+/// Double quotation marks are preferred:
+///   - If the string only contains single or double quotation marks, the other type will be used for enclosing the string
+///   - If both types are present, the double quotation marks will be escaped (replaced by `""`)
+///     and double quotation marks will be used to enclose the string
+#[cfg(any(feature = "postgres", feature = "sqlserver"))]
+pub(crate) fn simple_key_value_encode(s: &str) -> String {
+    let quotes_needed =
+        str_includes_control_char(s) || s.starts_with(' ') || s.ends_with(' ') || s.contains(';');
+
+    if !quotes_needed {
+        return s.to_string();
+    }
+
+    let includes_double_quotation = s.contains('"');
+    let includes_single_quotation = s.contains('\'');
+
+    if !includes_double_quotation {
+        return format!("\"{s}\"");
+    }
+
+    if !includes_single_quotation {
+        return format!("'{s}'");
+    }
+
+    let s = s.replace('"', "\"\"");
+
+    format!("\"{s}\"")
+}
+
+/// Checks if the given &str contains a control character by using [`char::is_control`]
+#[cfg(any(feature = "postgres", feature = "sqlserver"))]
+fn str_includes_control_char(s: &str) -> bool {
+    s.chars().any(char::is_control)
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(any(feature = "postgres", feature = "sqlserver"))]
+    use crate::encode::simple_key_value_encode;
+    use crate::encode::simple_percent_encode;
+    #[cfg(any(
+        feature = "postgres",
+        feature = "clickhouse",
+        feature = "mysql",
+        feature = "sqlite",
+        feature = "mongodb"
+    ))]
+    use crate::encode::simple_query_encode;
+    use crate::encode::{percent_decode, percent_encode, DecodeError};
+
+    #[test]
+    /// Test functionality of [`simple_percent_encode`]
+    fn test_simple_percent_encode() {
+        assert_eq!(
+            simple_percent_encode("!#$&'()*+,/:;=?@[]"),
+            "%21%23%24%26%27%28%29%2A%2B%2C%2F%3A%3B%3D%3F%40%5B%5D"
+        );
+        assert_eq!(simple_percent_encode("test!"), "test%21");
+
+        // Unreserved characters are kept
+        assert_eq!(simple_percent_encode("AZaz09-._~"), "AZaz09-._~");
+
+        // Space, `%` and multibyte characters
+        assert_eq!(simple_percent_encode("a b"), "a%20b");
+        assert_eq!(simple_percent_encode("100%"), "100%25");
+        assert_eq!(simple_percent_encode("ä"), "%C3%A4");
+        assert_eq!(
+            simple_percent_encode("\"<>\\^`{|}"),
+            "%22%3C%3E%5C%5E%60%7B%7C%7D"
+        );
+    }
+
+    /// Test functionality of [`percent_decode`]
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(
+            percent_decode("%21%23%24%26%27%28%29%2A%2B%2C%2F%3A%3B%3D%3F%40%5B%5D").unwrap(),
+            "!#$&'()*+,/:;=?@[]"
+        );
+        assert_eq!(percent_decode("test%21").unwrap(), "test!");
+        assert_eq!(percent_decode("%c3%a4").unwrap(), "ä");
+        assert_eq!(percent_decode("").unwrap(), "");
+
+        // Malformed escapes
+        assert_eq!(percent_decode("100%"), Err(DecodeError { offset: 3 }));
+        assert_eq!(percent_decode("a%2"), Err(DecodeError { offset: 1 }));
+        assert_eq!(percent_decode("%41%zz"), Err(DecodeError { offset: 3 }));
+        assert_eq!(percent_decode("%+1"), Err(DecodeError { offset: 0 }));
+        assert_eq!(
+            percent_decode("ä%").unwrap_err().to_string(),
+            "malformed percent-encoding at byte 2: `%` must be followed by two hex digits"
+        );
+
+        // Round trip
+        for s in [
+            "",
+            "plain",
+            "p@ss:w/rd?",
+            "100%",
+            "a b+c",
+            "ä🥙",
+            "\"<>\\^`{|}\n",
+        ] {
+            assert_eq!(percent_decode(&percent_encode(s)).unwrap(), s);
+        }
+    }
+
+    /// Test functionality of [`simple_query_encode`]
+    #[cfg(any(
+        feature = "postgres",
+        feature = "clickhouse",
+        feature = "mysql",
+        feature = "sqlite",
+        feature = "mongodb"
+    ))]
+    #[test]
+    fn test_simple_query_encode() {
+        assert_eq!(simple_query_encode("&=#% \n"), "%26%3D%23%25%20%0A");
+        assert_eq!(simple_query_encode("/path:to@file?"), "/path:to@file?");
+        assert_eq!(simple_query_encode("\u{85}"), "%C2%85");
+
+        // `+` and multibyte characters
+        assert_eq!(simple_query_encode("a+b"), "a%2Bb");
+        assert_eq!(simple_query_encode("a+b ä"), "a%2Bb%20%C3%A4");
+
+        // Characters which are legal in query values are kept
+        assert_eq!(simple_query_encode("!password,!md5"), "!password,!md5");
+        assert_eq!(simple_query_encode("a,b!c;d:e"), "a,b!c;d:e");
+
+        // Compared to the encoding used for the authority
+        assert_eq!(simple_percent_encode("/path:to"), "%2Fpath%3Ato");
+    }
+
+    /// Test functionality of [`encode_host`]
+    #[cfg(any(
+        feature = "postgres",
+        feature = "hive_metastore",
+        feature = "clickhouse",
+        feature = "mysql",
+        feature = "mongodb",
+        feature = "eventstoredb",
+        feature = "jdbc"
+    ))]
+    #[test]
+    fn test_encode_host() {
+        use crate::encode::encode_host;
+
+        assert_eq!(encode_host("db.example.com"), "db.example.com");
+        assert_eq!(encode_host("my host"), "my%20host");
+        assert_eq!(encode_host("::1"), "[::1]");
+        assert_eq!(encode_host("[::1]"), "[::1]");
+        assert_eq!(encode_host("fe80::1%eth0"), "[fe80::1%25eth0]");
+    }
+
+    /// Test functionality of [`simple_path_encode`]
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_simple_path_encode() {
+        use crate::encode::simple_path_encode;
+
+        assert_eq!(
+            simple_path_encode("C:/data/my db.sqlite"),
+            "C:/data/my%20db.sqlite"
+        );
+        assert_eq!(simple_path_encode("/tmp/ä?.db"), "/tmp/%C3%A4%3F.db");
+    }
+
+    /// Test functionality of [`simple_key_value_encode`]
+    #[cfg(any(feature = "postgres", feature = "sqlserver"))]
+    #[test]
+    fn test_simple_key_value_encode() {
+        // No changes
+        assert_eq!(&simple_key_value_encode("a"), "a");
+        assert_eq!(&simple_key_value_encode("a a"), "a a");
+        assert_eq!(&simple_key_value_encode("a \"a"), "a \"a");
+        assert_eq!(&simple_key_value_encode("a' a"), "a' a");
+        assert_eq!(&simple_key_value_encode("a' \"a"), "a' \"a");
+
+        // Leading/trailing spaces
+        assert_eq!(&simple_key_value_encode(" a"), "\" a\"");
+        assert_eq!(&simple_key_value_encode("a "), "\"a \"");
+        assert_eq!(&simple_key_value_encode(" a "), "\" a \"");
+        assert_eq!(&simple_key_value_encode("🥙"), "🥙");
+        assert_eq!(&simple_key_value_encode("🥙 "), "\"🥙 \"");
+
+        // Semicolon
+        assert_eq!(&simple_key_value_encode("a;a"), "\"a;a\"");
+        assert_eq!(&simple_key_value_encode(" a;a"), "\" a;a\"");
+        assert_eq!(&simple_key_value_encode("a;a "), "\"a;a \"");
+        assert_eq!(&simple_key_value_encode(" a;a "), "\" a;a \"");
+
+        // Control characters
+        assert_eq!(&simple_key_value_encode("\0"), "\"\0\"");
+        assert_eq!(&simple_key_value_encode("a\0a"), "\"a\0a\"");
+
+        // Includes single quotation mark
+        assert_eq!(&simple_key_value_encode(" a'a"), "\" a'a\"");
+
+        // Includes double quotation mark
+        assert_eq!(&simple_key_value_encode(" a\"a"), "' a\"a'");
+
+        // Includes both quotation marks
+        assert_eq!(&simple_key_value_encode(" 'a\"a"), "\" 'a\"\"a\"");
+        assert_eq!(&simple_key_value_encode(" 'a\"\"a"), "\" 'a\"\"\"\"a\"");
+    }
+}
@@ -0,0 +1,147 @@
+//! Connection string generator for `FaunaDB`
+
+use std::fmt::{Debug, Display};
+
+use crate::{encode::simple_percent_encode, mask_secret};
+
+/// Default endpoint of `FaunaDB`
+const DEFAULT_ENDPOINT: &str = "https://db.fauna.com";
+
+/// Struct representing a connection descriptor for `FaunaDB`: `<endpoint>?secret=<secret>`
+///
+/// The secret is masked in the [`Debug`] output,
+/// but the connection string itself ([`Display`]) contains it in plain text.
+#[allow(clippy::module_name_repetitions)]
+pub struct FaunaDbConnectionString {
+    endpoint: Option<String>,
+    secret: Option<String>,
+}
+
+impl Default for FaunaDbConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaunaDbConnectionString {
+    /// Creates a new and empty [`FaunaDbConnectionString`]
+    ///
+    /// This function initializes a new [`FaunaDbConnectionString`] with empty values.
+    /// Without any further changes this results in the string `https://db.fauna.com`.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::faunadb::FaunaDbConnectionString;
+    ///
+    /// FaunaDbConnectionString::new()
+    ///   .set_endpoint("https://db.fauna.com")
+    ///   .set_secret("secret");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            endpoint: None,
+            secret: None,
+        }
+    }
+
+    /// Sets/Replaces the endpoint (defaults to `https://db.fauna.com`)
+    ///
+    /// A trailing slash is removed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::faunadb::FaunaDbConnectionString;
+    ///
+    /// let conn_string = FaunaDbConnectionString::new().set_endpoint("http://localhost:8443/");
+    ///
+    /// assert_eq!(conn_string.to_string(), "http://localhost:8443");
+    /// ```
+    #[must_use]
+    pub fn set_endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sets/Replaces the secret (key or token)
+    ///
+    /// Parameters: `secret=<secret>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::faunadb::FaunaDbConnectionString;
+    ///
+    /// let conn_string = FaunaDbConnectionString::new().set_secret("secret");
+    ///
+    /// assert_eq!(conn_string.to_string(), "https://db.fauna.com?secret=secret");
+    /// ```
+    #[must_use]
+    pub fn set_secret(mut self, secret: &str) -> Self {
+        self.secret = Some(simple_percent_encode(secret));
+        self
+    }
+}
+
+impl Debug for FaunaDbConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FaunaDbConnectionString")
+            .field("endpoint", &self.endpoint)
+            .field("secret", &mask_secret(self.secret.as_ref()))
+            .finish()
+    }
+}
+
+impl Display for FaunaDbConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.endpoint.as_deref().unwrap_or(DEFAULT_ENDPOINT)
+        )?;
+
+        if let Some(secret) = &self.secret {
+            write!(f, "?secret={secret}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FaunaDbConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = FaunaDbConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "https://db.fauna.com");
+    }
+
+    /// Test custom endpoints
+    #[test]
+    fn test_set_endpoint() {
+        let conn_string = FaunaDbConnectionString::new().set_endpoint("http://Host:8443");
+        assert_eq!(&conn_string.to_string(), "http://Host:8443");
+
+        let conn_string = conn_string
+            .set_endpoint("https://db.us.fauna.com/")
+            .set_secret("fnSecret+/");
+        assert_eq!(
+            &conn_string.to_string(),
+            "https://db.us.fauna.com?secret=fnSecret%2B%2F"
+        );
+    }
+
+    /// Test that the secret is masked in the debug output
+    #[test]
+    fn test_debug_masks_secret() {
+        let conn_string = FaunaDbConnectionString::new().set_secret("fnSecret");
+        assert_eq!(
+            format!("{conn_string:?}"),
+            "FaunaDbConnectionString { endpoint: None, secret: Some(\"***\") }"
+        );
+    }
+}
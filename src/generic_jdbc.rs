@@ -0,0 +1,175 @@
+//! Connection string generator for databases which are accessed via a generic JDBC URL
+
+use std::fmt::Display;
+
+use crate::{HostPort, HostSpec};
+
+/// Struct representing a generic JDBC connection string:
+/// `jdbc:<subprotocol>://<host>:<port>/<db_name>;<key>=<value>`
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct GenericJdbcConnectionString {
+    subprotocol: String,
+    hostspec: Option<HostSpec>,
+    database: Option<String>,
+    properties: Vec<(String, String)>,
+}
+
+impl GenericJdbcConnectionString {
+    /// Creates a new and empty [`GenericJdbcConnectionString`] for the given JDBC subprotocol
+    ///
+    /// Without any further changes this results in the string `jdbc:<subprotocol>://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::generic_jdbc::GenericJdbcConnectionString;
+    ///
+    /// GenericJdbcConnectionString::new("trafodion")
+    ///   .set_host_with_port("localhost", 23400)
+    ///   .set_database_name("db_name")
+    ///   .set_property("schema", "seabase");
+    /// ```
+    #[must_use]
+    pub fn new(subprotocol: &str) -> Self {
+        Self {
+            subprotocol: subprotocol.to_string(),
+            hostspec: None,
+            database: None,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Sets/Replaces the host and omits the port in the connection string
+    /// (this usually results in the usage of the default port)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::generic_jdbc::GenericJdbcConnectionString;
+    ///
+    /// GenericJdbcConnectionString::new("trafodion").set_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_default_port(mut self, host: &str) -> Self {
+        self.hostspec = Some(HostSpec::Host(host.to_string()));
+        self
+    }
+
+    /// Sets/Replaces the host and the port
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::generic_jdbc::GenericJdbcConnectionString;
+    ///
+    /// GenericJdbcConnectionString::new("trafodion").set_host_with_port("localhost", 23400);
+    /// ```
+    #[must_use]
+    pub fn set_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.hostspec = Some(HostSpec::HostPort(HostPort {
+            host: host.to_string(),
+            port,
+        }));
+        self
+    }
+
+    /// Sets/Replaces the database name
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::generic_jdbc::GenericJdbcConnectionString;
+    ///
+    /// GenericJdbcConnectionString::new("trafodion").set_database_name("db_name");
+    /// ```
+    #[must_use]
+    pub fn set_database_name(mut self, db_name: &str) -> Self {
+        self.database = Some(db_name.to_string());
+        self
+    }
+
+    /// Sets/Replaces a `;<key>=<value>` property
+    ///
+    /// Properties are rendered in the order they have been set for the first time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::generic_jdbc::GenericJdbcConnectionString;
+    ///
+    /// GenericJdbcConnectionString::new("trafodion").set_property("schema", "seabase");
+    /// ```
+    #[must_use]
+    pub fn set_property(mut self, key: &str, value: &str) -> Self {
+        match self
+            .properties
+            .iter_mut()
+            .find(|(existing_key, _)| existing_key == key)
+        {
+            Some((_, existing_value)) => *existing_value = value.to_string(),
+            None => self.properties.push((key.to_string(), value.to_string())),
+        }
+        self
+    }
+}
+
+impl Display for GenericJdbcConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "jdbc:{}://", self.subprotocol)?;
+
+        if let Some(hostspec) = &self.hostspec {
+            write!(f, "{hostspec}")?;
+        }
+
+        if let Some(database) = &self.database {
+            write!(f, "/{database}")?;
+        }
+
+        for (key, value) in &self.properties {
+            write!(f, ";{key}={value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GenericJdbcConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = GenericJdbcConnectionString::new("trafodion");
+        assert_eq!(&conn_string.to_string(), "jdbc:trafodion://");
+    }
+
+    /// Test different subprotocols
+    #[test]
+    fn test_subprotocols() {
+        let conn_string = GenericJdbcConnectionString::new("trafodion")
+            .set_host_with_port("Host", 23400)
+            .set_database_name("DbName");
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:trafodion://Host:23400/DbName"
+        );
+
+        let conn_string = GenericJdbcConnectionString::new("informix-sqli")
+            .set_host_with_default_port("Host")
+            .set_database_name("DbName");
+        assert_eq!(&conn_string.to_string(), "jdbc:informix-sqli://Host/DbName");
+    }
+
+    /// Test property rendering
+    #[test]
+    fn test_set_property() {
+        let conn_string = GenericJdbcConnectionString::new("trafodion")
+            .set_host_with_port("Host", 23400)
+            .set_property("schema", "seabase")
+            .set_property("user", "User")
+            .set_property("schema", "other");
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:trafodion://Host:23400;schema=other;user=User"
+        );
+    }
+}
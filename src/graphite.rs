@@ -0,0 +1,165 @@
+//! Connection string generator for `Graphite`/`Carbon` ingestion endpoints
+
+use std::fmt::Display;
+
+/// Protocol used to send metrics to `Carbon`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphiteProtocol {
+    /// Plaintext protocol over TCP (default port `2003`)
+    Tcp,
+    /// Plaintext protocol over UDP (default port `2003`)
+    Udp,
+    /// Pickle protocol over TCP (default port `2004`)
+    Pickle,
+}
+
+impl GraphiteProtocol {
+    /// Default `Carbon` port for the protocol
+    fn default_port(self) -> usize {
+        match self {
+            Self::Tcp | Self::Udp => 2003,
+            Self::Pickle => 2004,
+        }
+    }
+}
+
+impl Display for GraphiteProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tcp => write!(f, "tcp"),
+            Self::Udp => write!(f, "udp"),
+            Self::Pickle => write!(f, "pickle"),
+        }
+    }
+}
+
+/// Struct representing a `Graphite`/`Carbon` ingestion endpoint: `<protocol>://<host>:<port>`
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct GraphiteConnectionString {
+    host: Option<String>,
+    port: Option<usize>,
+    protocol: GraphiteProtocol,
+}
+
+impl Default for GraphiteConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphiteConnectionString {
+    /// Creates a new and empty [`GraphiteConnectionString`] using [`GraphiteProtocol::Tcp`]
+    ///
+    /// Without any further changes this results in the string `tcp://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::graphite::{GraphiteConnectionString, GraphiteProtocol};
+    ///
+    /// GraphiteConnectionString::new()
+    ///   .set_host_with_port("localhost", 2004)
+    ///   .set_protocol(GraphiteProtocol::Pickle);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            host: None,
+            port: None,
+            protocol: GraphiteProtocol::Tcp,
+        }
+    }
+
+    /// Sets/Replaces the host and uses the default port of the selected protocol
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::graphite::GraphiteConnectionString;
+    ///
+    /// GraphiteConnectionString::new().set_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_default_port(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self.port = None;
+        self
+    }
+
+    /// Sets/Replaces the host and the port
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::graphite::GraphiteConnectionString;
+    ///
+    /// GraphiteConnectionString::new().set_host_with_port("localhost", 2003);
+    /// ```
+    #[must_use]
+    pub fn set_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.host = Some(host.to_string());
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets/Replaces the protocol
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::graphite::{GraphiteConnectionString, GraphiteProtocol};
+    ///
+    /// GraphiteConnectionString::new().set_protocol(GraphiteProtocol::Udp);
+    /// ```
+    #[must_use]
+    pub fn set_protocol(mut self, protocol: GraphiteProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+}
+
+impl Display for GraphiteConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}://", self.protocol)?;
+
+        if let Some(host) = &self.host {
+            let port = self.port.unwrap_or(self.protocol.default_port());
+            write!(f, "{host}:{port}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GraphiteConnectionString, GraphiteProtocol};
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = GraphiteConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "tcp://");
+    }
+
+    /// Test each protocol with its default port
+    #[test]
+    fn test_protocols() {
+        let conn_string = GraphiteConnectionString::new().set_host_with_default_port("Host");
+        assert_eq!(&conn_string.to_string(), "tcp://Host:2003");
+
+        let conn_string = conn_string.set_protocol(GraphiteProtocol::Udp);
+        assert_eq!(&conn_string.to_string(), "udp://Host:2003");
+
+        let conn_string = conn_string.set_protocol(GraphiteProtocol::Pickle);
+        assert_eq!(&conn_string.to_string(), "pickle://Host:2004");
+    }
+
+    /// Test explicit port
+    #[test]
+    fn test_set_host_with_port() {
+        let conn_string = GraphiteConnectionString::new()
+            .set_protocol(GraphiteProtocol::Pickle)
+            .set_host_with_port("Host", 2013);
+        assert_eq!(&conn_string.to_string(), "pickle://Host:2013");
+    }
+}
@@ -0,0 +1,173 @@
+//! Connection string generator for `H2` (JDBC)
+
+use std::fmt::Display;
+
+use crate::HostPort;
+
+/// The way the database is accessed
+#[derive(Debug)]
+enum Mode {
+    /// In-memory database (`jdbc:h2:mem:<name>`)
+    InMemory { name: String },
+    /// Embedded database file (`jdbc:h2:file:<path>`)
+    File { path: String },
+    /// Server mode (`jdbc:h2:tcp://<host>:<port>/<db_name>`)
+    Tcp {
+        host_port: HostPort,
+        db_name: String,
+    },
+}
+
+/// Struct representing an `H2` JDBC connection string
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct H2ConnectionString {
+    mode: Mode,
+    options: Vec<(String, String)>,
+}
+
+impl H2ConnectionString {
+    /// Creates a new [`H2ConnectionString`] for a (named) in-memory database
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::h2::H2ConnectionString;
+    ///
+    /// let conn_string = H2ConnectionString::in_memory("testdb");
+    ///
+    /// assert_eq!(conn_string.to_string(), "jdbc:h2:mem:testdb");
+    /// ```
+    #[must_use]
+    pub fn in_memory(name: &str) -> Self {
+        Self {
+            mode: Mode::InMemory {
+                name: name.to_string(),
+            },
+            options: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`H2ConnectionString`] for an embedded database located at the given path
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::h2::H2ConnectionString;
+    ///
+    /// let conn_string = H2ConnectionString::file("/data/db_name");
+    ///
+    /// assert_eq!(conn_string.to_string(), "jdbc:h2:file:/data/db_name");
+    /// ```
+    #[must_use]
+    pub fn file(path: &str) -> Self {
+        Self {
+            mode: Mode::File {
+                path: path.to_string(),
+            },
+            options: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`H2ConnectionString`] for a database served by an `H2` TCP server
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::h2::H2ConnectionString;
+    ///
+    /// let conn_string = H2ConnectionString::tcp("localhost", 9092, "db_name");
+    ///
+    /// assert_eq!(conn_string.to_string(), "jdbc:h2:tcp://localhost:9092/db_name");
+    /// ```
+    #[must_use]
+    pub fn tcp(host: &str, port: usize, db_name: &str) -> Self {
+        Self {
+            mode: Mode::Tcp {
+                host_port: HostPort {
+                    host: host.to_string(),
+                    port,
+                },
+                db_name: db_name.to_string(),
+            },
+            options: Vec::new(),
+        }
+    }
+
+    /// Sets/Replaces a `;<key>=<value>` option (e.g. `DB_CLOSE_DELAY=-1`)
+    ///
+    /// Options are rendered in the order they have been set for the first time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::h2::H2ConnectionString;
+    ///
+    /// H2ConnectionString::in_memory("testdb").set_option("DB_CLOSE_DELAY", "-1");
+    /// ```
+    #[must_use]
+    pub fn set_option(mut self, key: &str, value: &str) -> Self {
+        match self
+            .options
+            .iter_mut()
+            .find(|(existing_key, _)| existing_key == key)
+        {
+            Some((_, existing_value)) => *existing_value = value.to_string(),
+            None => self.options.push((key.to_string(), value.to_string())),
+        }
+        self
+    }
+}
+
+impl Display for H2ConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.mode {
+            Mode::InMemory { name } => write!(f, "jdbc:h2:mem:{name}")?,
+            Mode::File { path } => write!(f, "jdbc:h2:file:{path}")?,
+            Mode::Tcp {
+                host_port: HostPort { host, port },
+                db_name,
+            } => write!(f, "jdbc:h2:tcp://{host}:{port}/{db_name}")?,
+        }
+
+        for (key, value) in &self.options {
+            write!(f, ";{key}={value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::H2ConnectionString;
+
+    /// Test in-memory mode
+    #[test]
+    fn test_in_memory() {
+        let conn_string = H2ConnectionString::in_memory("TestDb");
+        assert_eq!(&conn_string.to_string(), "jdbc:h2:mem:TestDb");
+
+        let conn_string = conn_string.set_option("DB_CLOSE_DELAY", "-1");
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:h2:mem:TestDb;DB_CLOSE_DELAY=-1"
+        );
+    }
+
+    /// Test embedded file mode
+    #[test]
+    fn test_file() {
+        let conn_string = H2ConnectionString::file("/data/DbName")
+            .set_option("MODE", "PostgreSQL")
+            .set_option("AUTO_SERVER", "TRUE")
+            .set_option("MODE", "MySQL");
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:h2:file:/data/DbName;MODE=MySQL;AUTO_SERVER=TRUE"
+        );
+    }
+
+    /// Test TCP server mode
+    #[test]
+    fn test_tcp() {
+        let conn_string = H2ConnectionString::tcp("Host", 9092, "DbName");
+        assert_eq!(&conn_string.to_string(), "jdbc:h2:tcp://Host:9092/DbName");
+    }
+}
@@ -0,0 +1,156 @@
+//! Connection string generator for the `Apache Hive Metastore` (Thrift)
+
+use std::fmt::Display;
+
+use crate::{encode::encode_host, HostPort};
+
+/// Default port of the `Hive Metastore` Thrift service
+const DEFAULT_PORT: usize = 9083;
+
+/// Struct representing a list of `Hive Metastore` Thrift URIs
+/// (e.g. the value of `hive.metastore.uris`)
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct HiveMetastoreConnectionString {
+    hosts: Vec<HostPort>,
+    separator: char,
+}
+
+impl Default for HiveMetastoreConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HiveMetastoreConnectionString {
+    /// Creates a new and empty [`HiveMetastoreConnectionString`]
+    ///
+    /// This function initializes a new [`HiveMetastoreConnectionString`] without any metastore endpoints.
+    /// Without any further changes this results in an empty string which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::hive_metastore::HiveMetastoreConnectionString;
+    ///
+    /// HiveMetastoreConnectionString::new()
+    ///   .add_host_with_port("metastore1", 9083)
+    ///   .add_host_with_default_port("metastore2");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hosts: Vec::new(),
+            separator: ',',
+        }
+    }
+
+    /// Adds a metastore endpoint with the given host and port
+    ///
+    /// IPv6 addresses are automatically enclosed in brackets (`thrift://[::1]:9083`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::hive_metastore::HiveMetastoreConnectionString;
+    ///
+    /// HiveMetastoreConnectionString::new().add_host_with_port("localhost", 9083);
+    /// ```
+    #[must_use]
+    pub fn add_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.hosts.push(HostPort {
+            host: encode_host(host),
+            port,
+        });
+        self
+    }
+
+    /// Adds a metastore endpoint with the given host and the default port (`9083`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::hive_metastore::HiveMetastoreConnectionString;
+    ///
+    /// HiveMetastoreConnectionString::new().add_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn add_host_with_default_port(self, host: &str) -> Self {
+        self.add_host_with_port(host, DEFAULT_PORT)
+    }
+
+    /// Separates the URIs with spaces instead of commas
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::hive_metastore::HiveMetastoreConnectionString;
+    ///
+    /// HiveMetastoreConnectionString::new().use_space_separator();
+    /// ```
+    #[must_use]
+    pub fn use_space_separator(mut self) -> Self {
+        self.separator = ' ';
+        self
+    }
+}
+
+impl Display for HiveMetastoreConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let conn_string = self
+            .hosts
+            .iter()
+            .map(|HostPort { host, port }| format!("thrift://{host}:{port}"))
+            .collect::<Vec<String>>()
+            .join(&self.separator.to_string());
+
+        write!(f, "{conn_string}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HiveMetastoreConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = HiveMetastoreConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "");
+    }
+
+    /// Test a single metastore endpoint
+    #[test]
+    fn test_single_host() {
+        let conn_string = HiveMetastoreConnectionString::new().add_host_with_default_port("Host");
+        assert_eq!(&conn_string.to_string(), "thrift://Host:9083");
+    }
+
+    /// Test multiple metastore endpoints
+    #[test]
+    fn test_multiple_hosts() {
+        let conn_string = HiveMetastoreConnectionString::new()
+            .add_host_with_port("Host1", 9083)
+            .add_host_with_port("Host2", 9084);
+        assert_eq!(
+            &conn_string.to_string(),
+            "thrift://Host1:9083,thrift://Host2:9084"
+        );
+
+        let conn_string = conn_string.use_space_separator();
+        assert_eq!(
+            &conn_string.to_string(),
+            "thrift://Host1:9083 thrift://Host2:9084"
+        );
+    }
+
+    /// Test IPv6 addresses (with and without brackets)
+    #[test]
+    fn test_ipv6_host() {
+        let conn_string = HiveMetastoreConnectionString::new()
+            .add_host_with_default_port("::1")
+            .add_host_with_port("[fe80::1]", 9084);
+        assert_eq!(
+            &conn_string.to_string(),
+            "thrift://[::1]:9083,thrift://[fe80::1]:9084"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Connection string generator for `Memcached`
+
+use std::fmt::Display;
+
+use crate::{encode::simple_percent_encode, HostPort};
+
+/// Default port of a `Memcached` server
+const DEFAULT_PORT: usize = 11211;
+
+/// A single server of the connection string (with an optional weight)
+#[derive(Debug)]
+struct Server {
+    host_port: HostPort,
+    weight: Option<usize>,
+}
+
+impl Display for Server {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let HostPort { host, port } = &self.host_port;
+        write!(f, "{host}:{port}")?;
+
+        if let Some(weight) = self.weight {
+            write!(f, ":{weight}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Struct representing a `Memcached` connection string (a list of servers)
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MemcachedConnectionString {
+    servers: Vec<Server>,
+}
+
+impl Default for MemcachedConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemcachedConnectionString {
+    /// Creates a new and empty [`MemcachedConnectionString`]
+    ///
+    /// This function initializes a new [`MemcachedConnectionString`] without any servers.
+    /// Without any further changes this results in the string `memcached://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::memcached::MemcachedConnectionString;
+    ///
+    /// MemcachedConnectionString::new()
+    ///   .add_host_with_default_port("host1")
+    ///   .add_host_with_port_and_weight("host2", 11211, 2);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            servers: Vec::new(),
+        }
+    }
+
+    /// Adds a server with the given host and the default port (`11211`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::memcached::MemcachedConnectionString;
+    ///
+    /// MemcachedConnectionString::new().add_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn add_host_with_default_port(self, host: &str) -> Self {
+        self.add_host_with_port(host, DEFAULT_PORT)
+    }
+
+    /// Adds a server with the given host and port
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::memcached::MemcachedConnectionString;
+    ///
+    /// MemcachedConnectionString::new().add_host_with_port("localhost", 11211);
+    /// ```
+    #[must_use]
+    pub fn add_host_with_port(self, host: &str, port: usize) -> Self {
+        self.add_server(host, port, None)
+    }
+
+    /// Adds a server with the given host, port and weight (`host:port:weight`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::memcached::MemcachedConnectionString;
+    ///
+    /// MemcachedConnectionString::new().add_host_with_port_and_weight("localhost", 11211, 2);
+    /// ```
+    #[must_use]
+    pub fn add_host_with_port_and_weight(self, host: &str, port: usize, weight: usize) -> Self {
+        self.add_server(host, port, Some(weight))
+    }
+
+    /// Adds a server
+    #[must_use]
+    fn add_server(mut self, host: &str, port: usize, weight: Option<usize>) -> Self {
+        self.servers.push(Server {
+            host_port: HostPort {
+                host: simple_percent_encode(host),
+                port,
+            },
+            weight,
+        });
+        self
+    }
+}
+
+impl Display for MemcachedConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let servers: Vec<String> = self.servers.iter().map(ToString::to_string).collect();
+
+        write!(f, "memcached://{}", servers.join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MemcachedConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = MemcachedConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "memcached://");
+    }
+
+    /// Test multiple servers
+    #[test]
+    fn test_multiple_servers() {
+        let conn_string = MemcachedConnectionString::new()
+            .add_host_with_default_port("host1")
+            .add_host_with_port("host2", 11212);
+        assert_eq!(
+            &conn_string.to_string(),
+            "memcached://host1:11211,host2:11212"
+        );
+    }
+
+    /// Test weighted server
+    #[test]
+    fn test_weighted_server() {
+        let conn_string = MemcachedConnectionString::new()
+            .add_host_with_default_port("host1")
+            .add_host_with_port_and_weight("host2", 11211, 3);
+        assert_eq!(
+            &conn_string.to_string(),
+            "memcached://host1:11211,host2:11211:3"
+        );
+    }
+}
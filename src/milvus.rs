@@ -0,0 +1,185 @@
+//! Connection string generator for `Milvus`
+
+use std::fmt::{Debug, Display};
+
+use crate::{encode::simple_percent_encode, mask_secret};
+
+/// Default port of the `Milvus` gRPC endpoint
+const DEFAULT_PORT: usize = 19530;
+
+/// Struct representing a connection descriptor for `Milvus`: `http(s)://host:port?token=<token>`
+///
+/// The URI part (`http(s)://host:port`) is what the official clients expect as `uri`.
+/// The token is masked in the [`Debug`] output,
+/// but the connection string itself ([`Display`]) contains it in plain text.
+#[allow(clippy::module_name_repetitions)]
+pub struct MilvusConnectionString {
+    host: Option<String>,
+    port: Option<usize>,
+    tls: bool,
+    token: Option<String>,
+}
+
+impl Default for MilvusConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MilvusConnectionString {
+    /// Creates a new and empty [`MilvusConnectionString`]
+    ///
+    /// This function initializes a new [`MilvusConnectionString`] with empty values.
+    /// Without any further changes this results in the string `http://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::milvus::MilvusConnectionString;
+    ///
+    /// MilvusConnectionString::new()
+    ///   .set_host_with_default_port("localhost")
+    ///   .use_tls()
+    ///   .set_token("user:password");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            host: None,
+            port: None,
+            tls: false,
+            token: None,
+        }
+    }
+
+    /// Sets/Replaces the host and uses the default port (`19530`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::milvus::MilvusConnectionString;
+    ///
+    /// let conn_string = MilvusConnectionString::new().set_host_with_default_port("localhost");
+    ///
+    /// assert_eq!(conn_string.to_string(), "http://localhost:19530");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_default_port(mut self, host: &str) -> Self {
+        self.host = Some(simple_percent_encode(host));
+        self.port = None;
+        self
+    }
+
+    /// Sets/Replaces the host and the port
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::milvus::MilvusConnectionString;
+    ///
+    /// MilvusConnectionString::new().set_host_with_port("localhost", 19530);
+    /// ```
+    #[must_use]
+    pub fn set_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.host = Some(simple_percent_encode(host));
+        self.port = Some(port);
+        self
+    }
+
+    /// Uses TLS (`https://`) instead of plain HTTP
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::milvus::MilvusConnectionString;
+    ///
+    /// MilvusConnectionString::new().use_tls();
+    /// ```
+    #[must_use]
+    pub fn use_tls(mut self) -> Self {
+        self.tls = true;
+        self
+    }
+
+    /// Sets/Replaces the token (API key or `<user>:<password>`)
+    ///
+    /// Parameters: `token=<token>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::milvus::MilvusConnectionString;
+    ///
+    /// MilvusConnectionString::new().set_token("user:password");
+    /// ```
+    #[must_use]
+    pub fn set_token(mut self, token: &str) -> Self {
+        self.token = Some(simple_percent_encode(token));
+        self
+    }
+}
+
+impl Debug for MilvusConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MilvusConnectionString")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("tls", &self.tls)
+            .field("token", &mask_secret(self.token.as_ref()))
+            .finish()
+    }
+}
+
+impl Display for MilvusConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let scheme = if self.tls { "https" } else { "http" };
+        write!(f, "{scheme}://")?;
+
+        if let Some(host) = &self.host {
+            let port = self.port.unwrap_or(DEFAULT_PORT);
+            write!(f, "{host}:{port}")?;
+        }
+
+        if let Some(token) = &self.token {
+            write!(f, "?token={token}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MilvusConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = MilvusConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "http://");
+    }
+
+    /// Test the default port and TLS
+    #[test]
+    fn test_default_port() {
+        let conn_string = MilvusConnectionString::new().set_host_with_default_port("Host");
+        assert_eq!(&conn_string.to_string(), "http://Host:19530");
+
+        let conn_string = conn_string.set_host_with_port("Host", 443).use_tls();
+        assert_eq!(&conn_string.to_string(), "https://Host:443");
+    }
+
+    /// Test the token parameter and that the token is masked in the debug output
+    #[test]
+    fn test_token_masking() {
+        let conn_string = MilvusConnectionString::new()
+            .set_host_with_default_port("Host")
+            .set_token("User:Secret");
+        assert_eq!(
+            &conn_string.to_string(),
+            "http://Host:19530?token=User%3ASecret"
+        );
+
+        assert_eq!(
+            format!("{conn_string:?}"),
+            "MilvusConnectionString { host: Some(\"Host\"), port: None, tls: false, token: Some(\"***\") }"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Connection string generator for `Apache Phoenix` (JDBC, on top of `Apache HBase`)
+
+use std::fmt::Display;
+
+use crate::HostPort;
+
+/// Struct representing an `Apache Phoenix` JDBC connection string:
+/// `jdbc:phoenix:<zk_host>:<zk_port>,<zk_host>:<zk_port>:<znode_parent>`
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PhoenixConnectionString {
+    zookeepers: Vec<HostPort>,
+    znode_parent: Option<String>,
+}
+
+impl Default for PhoenixConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoenixConnectionString {
+    /// Creates a new and empty [`PhoenixConnectionString`]
+    ///
+    /// This function initializes a new [`PhoenixConnectionString`] with empty values.
+    /// Without any further changes this results in the string `jdbc:phoenix`
+    /// which makes the driver read the `ZooKeeper` quorum from the `HBase` configuration.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::phoenix::PhoenixConnectionString;
+    ///
+    /// PhoenixConnectionString::new()
+    ///   .set_zookeeper("zk1", 2181)
+    ///   .set_zookeeper("zk2", 2181)
+    ///   .set_znode_parent("/hbase");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            zookeepers: Vec::new(),
+            znode_parent: None,
+        }
+    }
+
+    /// Adds a `ZooKeeper` host of the quorum
+    ///
+    /// Can be called multiple times. The hosts are rendered comma-separated in the order they have been added.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::phoenix::PhoenixConnectionString;
+    ///
+    /// PhoenixConnectionString::new().set_zookeeper("localhost", 2181);
+    /// ```
+    #[must_use]
+    pub fn set_zookeeper(mut self, host: &str, port: usize) -> Self {
+        self.zookeepers.push(HostPort {
+            host: host.to_string(),
+            port,
+        });
+        self
+    }
+
+    /// Sets/Replaces the root znode of `HBase` in `ZooKeeper` (e.g. `/hbase`)
+    ///
+    /// Only rendered if at least one `ZooKeeper` host is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::phoenix::PhoenixConnectionString;
+    ///
+    /// PhoenixConnectionString::new().set_zookeeper("localhost", 2181).set_znode_parent("/hbase");
+    /// ```
+    #[must_use]
+    pub fn set_znode_parent(mut self, path: &str) -> Self {
+        self.znode_parent = Some(path.to_string());
+        self
+    }
+}
+
+impl Display for PhoenixConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "jdbc:phoenix")?;
+
+        if self.zookeepers.is_empty() {
+            return Ok(());
+        }
+
+        let quorum = self
+            .zookeepers
+            .iter()
+            .map(|HostPort { host, port }| format!("{host}:{port}"))
+            .collect::<Vec<String>>()
+            .join(",");
+        write!(f, ":{quorum}")?;
+
+        if let Some(znode_parent) = &self.znode_parent {
+            write!(f, ":{znode_parent}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PhoenixConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = PhoenixConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "jdbc:phoenix");
+
+        // The znode parent requires a quorum
+        let conn_string = conn_string.set_znode_parent("/hbase");
+        assert_eq!(&conn_string.to_string(), "jdbc:phoenix");
+    }
+
+    /// Test multiple `ZooKeeper` hosts
+    #[test]
+    fn test_set_zookeeper() {
+        let conn_string = PhoenixConnectionString::new().set_zookeeper("Zk1", 2181);
+        assert_eq!(&conn_string.to_string(), "jdbc:phoenix:Zk1:2181");
+
+        let conn_string = conn_string
+            .set_zookeeper("Zk2", 2181)
+            .set_zookeeper("Zk3", 2182);
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:phoenix:Zk1:2181,Zk2:2181,Zk3:2182"
+        );
+    }
+
+    /// Test the znode path
+    #[test]
+    fn test_set_znode_parent() {
+        let conn_string = PhoenixConnectionString::new()
+            .set_zookeeper("Zk1", 2181)
+            .set_zookeeper("Zk2", 2181)
+            .set_znode_parent("/hbase-unsecure");
+        assert_eq!(
+            &conn_string.to_string(),
+            "jdbc:phoenix:Zk1:2181,Zk2:2181:/hbase-unsecure"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Connection string generator for databases which expect positional instead of keyword parameters
+//! (e.g. ODBC-style `DSN;UID;PWD` strings of some legacy databases like `MaxDB`)
+
+use std::fmt::Display;
+
+/// Default separator between the components
+const DEFAULT_SEPARATOR: &str = ";";
+
+/// Struct representing a connection string consisting of ordered components: `<component>;<component>;...`
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PositionalConnectionString {
+    components: Vec<String>,
+    separator: String,
+}
+
+impl Default for PositionalConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PositionalConnectionString {
+    /// Creates a new and empty [`PositionalConnectionString`]
+    ///
+    /// This function initializes a new [`PositionalConnectionString`] without any components
+    /// which results in an empty string.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::positional::PositionalConnectionString;
+    ///
+    /// PositionalConnectionString::new()
+    ///   .add_component("dsn")
+    ///   .add_component("user")
+    ///   .add_component("password");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            components: Vec::new(),
+            separator: DEFAULT_SEPARATOR.to_string(),
+        }
+    }
+
+    /// Appends a component
+    ///
+    /// Components are rendered in the order they have been added.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::positional::PositionalConnectionString;
+    ///
+    /// PositionalConnectionString::new().add_component("dsn");
+    /// ```
+    #[must_use]
+    pub fn add_component(mut self, component: &str) -> Self {
+        self.components.push(component.to_string());
+        self
+    }
+
+    /// Sets/Replaces the separator between the components (default: `;`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::positional::PositionalConnectionString;
+    ///
+    /// let conn_string = PositionalConnectionString::new()
+    ///     .add_component("dsn")
+    ///     .add_component("user")
+    ///     .set_separator(",");
+    ///
+    /// assert_eq!(conn_string.to_string(), "dsn,user");
+    /// ```
+    #[must_use]
+    pub fn set_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+}
+
+impl Display for PositionalConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.components.join(&self.separator))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PositionalConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = PositionalConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "");
+    }
+
+    /// Test ordered rendering with the default separator
+    #[test]
+    fn test_add_component() {
+        let conn_string = PositionalConnectionString::new().add_component("Dsn");
+        assert_eq!(&conn_string.to_string(), "Dsn");
+
+        let conn_string = conn_string.add_component("User").add_component("Password");
+        assert_eq!(&conn_string.to_string(), "Dsn;User;Password");
+    }
+
+    /// Test ordered rendering with a custom separator
+    #[test]
+    fn test_set_separator() {
+        let conn_string = PositionalConnectionString::new()
+            .add_component("Dsn")
+            .add_component("User")
+            .add_component("Password")
+            .set_separator(", ");
+        assert_eq!(&conn_string.to_string(), "Dsn, User, Password");
+    }
+}
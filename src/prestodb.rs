@@ -0,0 +1,277 @@
+//! Connection string generator for `PrestoDB`
+
+use std::fmt::Display;
+
+use crate::encode::simple_percent_encode;
+
+/// Default port of the `Presto` coordinator
+const DEFAULT_PORT: usize = 8080;
+
+/// Struct representing a `PrestoDB` connection string: `presto://user@host:port/catalog/schema`
+///
+/// The access token and the source name are sent as HTTP headers instead of being part of the URL.
+/// They are available via [`PrestoDbConnectionString::headers`].
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PrestoDbConnectionString {
+    username: Option<String>,
+    host: Option<String>,
+    port: Option<usize>,
+    catalog: Option<String>,
+    schema: Option<String>,
+    access_token: Option<String>,
+    source: Option<String>,
+}
+
+impl Default for PrestoDbConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrestoDbConnectionString {
+    /// Creates a new and empty [`PrestoDbConnectionString`]
+    ///
+    /// This function initializes a new [`PrestoDbConnectionString`] with empty values.
+    /// Without any further changes this results in the string `presto://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new()
+    ///   .set_username("user")
+    ///   .set_host_with_default_port("localhost")
+    ///   .set_catalog("hive")
+    ///   .set_schema("default");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            username: None,
+            host: None,
+            port: None,
+            catalog: None,
+            schema: None,
+            access_token: None,
+            source: None,
+        }
+    }
+
+    /// Sets/Replaces the username
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_username("user");
+    /// ```
+    #[must_use]
+    pub fn set_username(mut self, username: &str) -> Self {
+        self.username = Some(simple_percent_encode(username));
+        self
+    }
+
+    /// Sets/Replaces the host and uses the default port (`8080`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_default_port(mut self, host: &str) -> Self {
+        self.host = Some(simple_percent_encode(host));
+        self.port = None;
+        self
+    }
+
+    /// Sets/Replaces the host and the port
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_host_with_port("localhost", 8080);
+    /// ```
+    #[must_use]
+    pub fn set_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.host = Some(simple_percent_encode(host));
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets/Replaces the catalog
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_catalog("hive");
+    /// ```
+    #[must_use]
+    pub fn set_catalog(mut self, catalog: &str) -> Self {
+        self.catalog = Some(simple_percent_encode(catalog));
+        self
+    }
+
+    /// Sets/Replaces the schema (only rendered if a catalog is set as well)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_catalog("hive").set_schema("default");
+    /// ```
+    #[must_use]
+    pub fn set_schema(mut self, schema: &str) -> Self {
+        self.schema = Some(simple_percent_encode(schema));
+        self
+    }
+
+    /// Sets/Replaces the access token
+    ///
+    /// Header: `Authorization: Bearer <token>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_access_token("token");
+    /// ```
+    #[must_use]
+    pub fn set_access_token(mut self, token: &str) -> Self {
+        self.access_token = Some(token.to_string());
+        self
+    }
+
+    /// Sets/Replaces the source name identifying the client application
+    ///
+    /// Header: `X-Presto-Source: <source>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// PrestoDbConnectionString::new().set_source("my_app");
+    /// ```
+    #[must_use]
+    pub fn set_source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_string());
+        self
+    }
+
+    /// Returns the HTTP headers which have to be sent alongside the requests
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::prestodb::PrestoDbConnectionString;
+    ///
+    /// let headers = PrestoDbConnectionString::new().set_source("my_app").headers();
+    ///
+    /// assert_eq!(
+    ///     headers,
+    ///     vec![(String::from("X-Presto-Source"), String::from("my_app"))]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = Vec::new();
+
+        if let Some(access_token) = &self.access_token {
+            headers.push((
+                String::from("Authorization"),
+                format!("Bearer {access_token}"),
+            ));
+        }
+
+        if let Some(source) = &self.source {
+            headers.push((String::from("X-Presto-Source"), source.clone()));
+        }
+
+        headers
+    }
+}
+
+impl Display for PrestoDbConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "presto://")?;
+
+        if let Some(username) = &self.username {
+            write!(f, "{username}@")?;
+        }
+
+        if let Some(host) = &self.host {
+            let port = self.port.unwrap_or(DEFAULT_PORT);
+            write!(f, "{host}:{port}")?;
+        }
+
+        if let Some(catalog) = &self.catalog {
+            write!(f, "/{catalog}")?;
+
+            if let Some(schema) = &self.schema {
+                write!(f, "/{schema}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PrestoDbConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = PrestoDbConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "presto://");
+        assert!(conn_string.headers().is_empty());
+    }
+
+    /// Test everything together
+    #[test]
+    fn test_all_together() {
+        let conn_string = PrestoDbConnectionString::new()
+            .set_username("user")
+            .set_host_with_default_port("Host")
+            .set_catalog("hive")
+            .set_schema("default");
+        assert_eq!(
+            &conn_string.to_string(),
+            "presto://user@Host:8080/hive/default"
+        );
+    }
+
+    /// Test that the access token is sent as header and not rendered into the URL
+    #[test]
+    fn test_set_access_token() {
+        let conn_string = PrestoDbConnectionString::new()
+            .set_host_with_port("Host", 443)
+            .set_access_token("secret");
+        assert_eq!(&conn_string.to_string(), "presto://Host:443");
+        assert_eq!(
+            conn_string.headers(),
+            vec![(String::from("Authorization"), String::from("Bearer secret"))]
+        );
+    }
+
+    /// Test the source header
+    #[test]
+    fn test_set_source() {
+        let conn_string = PrestoDbConnectionString::new()
+            .set_access_token("secret")
+            .set_source("my_app");
+        assert_eq!(
+            conn_string.headers(),
+            vec![
+                (String::from("Authorization"), String::from("Bearer secret")),
+                (String::from("X-Presto-Source"), String::from("my_app")),
+            ]
+        );
+    }
+}
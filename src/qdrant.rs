@@ -0,0 +1,226 @@
+//! Connection string generator for `Qdrant`
+
+use std::fmt::{Debug, Display};
+
+use crate::{encode::simple_percent_encode, mask_secret};
+
+/// Default port of the REST API
+const DEFAULT_REST_PORT: usize = 6333;
+
+/// Default port of the gRPC API
+const DEFAULT_GRPC_PORT: usize = 6334;
+
+/// The API used to access the database
+#[derive(Debug)]
+enum Api {
+    /// REST API (default port `6333`)
+    Rest,
+    /// gRPC API (default port `6334`)
+    Grpc,
+}
+
+impl Api {
+    /// Returns the default port of the API
+    fn default_port(&self) -> usize {
+        match self {
+            Self::Rest => DEFAULT_REST_PORT,
+            Self::Grpc => DEFAULT_GRPC_PORT,
+        }
+    }
+}
+
+/// Struct representing a connection descriptor for `Qdrant`: `http(s)://host:port?api-key=<api_key>`
+///
+/// The API key is masked in the [`Debug`] output,
+/// but the connection string itself ([`Display`]) contains it in plain text.
+#[allow(clippy::module_name_repetitions)]
+pub struct QdrantConnectionString {
+    api: Api,
+    tls: bool,
+    host: Option<String>,
+    port: Option<usize>,
+    api_key: Option<String>,
+}
+
+impl QdrantConnectionString {
+    /// Creates a new and empty [`QdrantConnectionString`] for the REST API (default port `6333`)
+    ///
+    /// Without any further changes this results in the string `http://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::qdrant::QdrantConnectionString;
+    ///
+    /// let conn_string = QdrantConnectionString::rest().set_host_with_default_port("localhost");
+    ///
+    /// assert_eq!(conn_string.to_string(), "http://localhost:6333");
+    /// ```
+    #[must_use]
+    pub fn rest() -> Self {
+        Self::with_api(Api::Rest)
+    }
+
+    /// Creates a new and empty [`QdrantConnectionString`] for the gRPC API (default port `6334`)
+    ///
+    /// Without any further changes this results in the string `http://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::qdrant::QdrantConnectionString;
+    ///
+    /// let conn_string = QdrantConnectionString::grpc().set_host_with_default_port("localhost");
+    ///
+    /// assert_eq!(conn_string.to_string(), "http://localhost:6334");
+    /// ```
+    #[must_use]
+    pub fn grpc() -> Self {
+        Self::with_api(Api::Grpc)
+    }
+
+    /// Creates a new and empty [`QdrantConnectionString`] for the given API
+    fn with_api(api: Api) -> Self {
+        Self {
+            api,
+            tls: false,
+            host: None,
+            port: None,
+            api_key: None,
+        }
+    }
+
+    /// Uses TLS (`https://`) instead of plain HTTP
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::qdrant::QdrantConnectionString;
+    ///
+    /// QdrantConnectionString::rest().use_tls();
+    /// ```
+    #[must_use]
+    pub fn use_tls(mut self) -> Self {
+        self.tls = true;
+        self
+    }
+
+    /// Sets/Replaces the host and uses the default port of the API
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::qdrant::QdrantConnectionString;
+    ///
+    /// QdrantConnectionString::rest().set_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_default_port(mut self, host: &str) -> Self {
+        self.host = Some(simple_percent_encode(host));
+        self.port = None;
+        self
+    }
+
+    /// Sets/Replaces the host and the port
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::qdrant::QdrantConnectionString;
+    ///
+    /// QdrantConnectionString::rest().set_host_with_port("localhost", 6333);
+    /// ```
+    #[must_use]
+    pub fn set_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.host = Some(simple_percent_encode(host));
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets/Replaces the API key
+    ///
+    /// Parameters: `api-key=<api_key>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::qdrant::QdrantConnectionString;
+    ///
+    /// QdrantConnectionString::rest().set_api_key("key");
+    /// ```
+    #[must_use]
+    pub fn set_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(simple_percent_encode(api_key));
+        self
+    }
+}
+
+impl Debug for QdrantConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QdrantConnectionString")
+            .field("api", &self.api)
+            .field("tls", &self.tls)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("api_key", &mask_secret(self.api_key.as_ref()))
+            .finish()
+    }
+}
+
+impl Display for QdrantConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let scheme = if self.tls { "https" } else { "http" };
+        write!(f, "{scheme}://")?;
+
+        if let Some(host) = &self.host {
+            let port = self.port.unwrap_or_else(|| self.api.default_port());
+            write!(f, "{host}:{port}")?;
+        }
+
+        if let Some(api_key) = &self.api_key {
+            write!(f, "?api-key={api_key}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::QdrantConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        assert_eq!(&QdrantConnectionString::rest().to_string(), "http://");
+        assert_eq!(&QdrantConnectionString::grpc().to_string(), "http://");
+    }
+
+    /// Test the default ports of both APIs
+    #[test]
+    fn test_default_ports() {
+        let conn_string = QdrantConnectionString::rest().set_host_with_default_port("Host");
+        assert_eq!(&conn_string.to_string(), "http://Host:6333");
+
+        let conn_string = QdrantConnectionString::grpc().set_host_with_default_port("Host");
+        assert_eq!(&conn_string.to_string(), "http://Host:6334");
+
+        let conn_string = conn_string.set_host_with_port("Host", 443).use_tls();
+        assert_eq!(&conn_string.to_string(), "https://Host:443");
+    }
+
+    /// Test the API key parameter and that the key is masked in the debug output
+    #[test]
+    fn test_api_key_masking() {
+        let conn_string = QdrantConnectionString::rest()
+            .set_host_with_default_port("Host")
+            .set_api_key("SecretKey");
+        assert_eq!(
+            &conn_string.to_string(),
+            "http://Host:6333?api-key=SecretKey"
+        );
+
+        assert_eq!(
+            format!("{conn_string:?}"),
+            "QdrantConnectionString { api: Rest, tls: false, host: Some(\"Host\"), port: None, api_key: Some(\"***\") }"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Connection string generator for `SQLite`
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::encode::{simple_path_encode, simple_percent_encode, simple_query_encode};
+
+/// The database which should be opened
+#[derive(Debug)]
+enum Location {
+    /// A database file (percent-encoded path)
+    File(String),
+    /// A temporary in-memory database
+    InMemory,
+}
+
+/// Struct representing a `SQLite` connection string: `sqlite://path/to/file.db?mode=rwc` or `sqlite::memory:`
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SqliteConnectionString {
+    location: Option<Location>,
+    parameter_list: BTreeMap<String, String>,
+}
+
+impl Default for SqliteConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SqliteConnectionString {
+    /// Creates a new and empty [`SqliteConnectionString`]
+    ///
+    /// This function initializes a new [`SqliteConnectionString`] with empty values.
+    /// Without any further changes this results in the string `sqlite://` which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlite::SqliteConnectionString;
+    ///
+    /// SqliteConnectionString::new()
+    ///   .set_file_path("data/app.db")
+    ///   .dangerously_set_parameter("mode", "rwc");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            location: None,
+            parameter_list: BTreeMap::new(),
+        }
+    }
+
+    /// Sets/Replaces the path of the database file (replaces the in-memory mode)
+    ///
+    /// Characters which aren't allowed in the path of a URI (e.g. spaces) are percent-encoded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlite::SqliteConnectionString;
+    ///
+    /// let conn_string = SqliteConnectionString::new().set_file_path("my data/app.db");
+    ///
+    /// assert_eq!(conn_string.to_string(), "sqlite://my%20data/app.db");
+    /// ```
+    #[must_use]
+    pub fn set_file_path(mut self, path: &str) -> Self {
+        self.location = Some(Location::File(simple_path_encode(path)));
+        self
+    }
+
+    /// Uses a temporary in-memory database (replaces the path of the database file)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlite::SqliteConnectionString;
+    ///
+    /// let conn_string = SqliteConnectionString::new().set_in_memory();
+    ///
+    /// assert_eq!(conn_string.to_string(), "sqlite::memory:");
+    /// ```
+    #[must_use]
+    pub fn set_in_memory(mut self) -> Self {
+        self.location = Some(Location::InMemory);
+        self
+    }
+
+    /// Sets/replaces ANY parameter even if it doesn't exist in the list of allowed/implemented parameters
+    ///
+    /// The value is encoded for the query string, so only `&`, `=`, `#`, `%`, `+`, spaces,
+    /// control characters and non-ASCII characters are percent-encoded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlite::SqliteConnectionString;
+    ///
+    /// SqliteConnectionString::new().dangerously_set_parameter("mode", "rwc");
+    /// ```
+    #[must_use]
+    pub fn dangerously_set_parameter(mut self, key: &str, value: &str) -> Self {
+        self.parameter_list
+            .insert(simple_percent_encode(key), simple_query_encode(value));
+        self
+    }
+}
+
+impl Display for SqliteConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(Location::File(path)) => write!(f, "sqlite://{path}")?,
+            Some(Location::InMemory) => write!(f, "sqlite::memory:")?,
+            None => write!(f, "sqlite://")?,
+        }
+
+        if !self.parameter_list.is_empty() {
+            let parameters: Vec<String> = self
+                .parameter_list
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect();
+
+            write!(f, "?{}", parameters.join("&"))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SqliteConnectionString;
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = SqliteConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "sqlite://");
+    }
+
+    /// Test percent-encoding of the file path
+    #[test]
+    fn test_set_file_path() {
+        let conn_string = SqliteConnectionString::new().set_file_path("/var/lib/app.db");
+        assert_eq!(&conn_string.to_string(), "sqlite:///var/lib/app.db");
+
+        let conn_string = conn_string.set_file_path("data/my app#1?.db");
+        assert_eq!(&conn_string.to_string(), "sqlite://data/my%20app%231%3F.db");
+
+        let conn_string = conn_string.dangerously_set_parameter("mode", "rwc");
+        assert_eq!(
+            &conn_string.to_string(),
+            "sqlite://data/my%20app%231%3F.db?mode=rwc"
+        );
+    }
+
+    /// Test that the in-memory mode and the file path replace each other
+    #[test]
+    fn test_set_in_memory() {
+        let conn_string = SqliteConnectionString::new()
+            .set_file_path("app.db")
+            .set_in_memory();
+        assert_eq!(&conn_string.to_string(), "sqlite::memory:");
+
+        let conn_string = conn_string.set_file_path("app.db");
+        assert_eq!(&conn_string.to_string(), "sqlite://app.db");
+    }
+}
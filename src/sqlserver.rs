@@ -1,21 +1,83 @@
 //! Connection string generator for `Microsoft SQL Server`
 
-use std::{
-    cmp::{max, min},
-    collections::HashMap,
-    fmt::Display,
+use std::{collections::BTreeMap, fmt::Display, fmt::Write};
+
+use crate::{
+    encode::simple_key_value_encode, DuplicateKeyError, HostPort, PortError, UsernamePassword,
 };
 
+/// Replacement for the password in masked output
+const MASKED_PASSWORD: &str = "***";
+
+/// Parameters which are managed by typed setters
+const TYPED_PARAMETERS: [&str; 13] = [
+    "ApplicationIntent",
+    "MultipleActiveResultSets",
+    "command timeout",
+    "connectRetryCount",
+    "connectRetryInterval",
+    "database",
+    "encrypt",
+    "initial catalog",
+    "password",
+    "server",
+    "timeout",
+    "trustServerCertificate",
+    "user",
+];
+
+/// The spelling of the database parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DatabaseKeyStyle {
+    /// `database=<db_name>`
+    Database,
+    /// `initial catalog=<db_name>` (ADO.NET)
+    InitialCatalog,
+}
+
+impl DatabaseKeyStyle {
+    /// Returns the parameter key
+    fn key(self) -> &'static str {
+        match self {
+            Self::Database => "database",
+            Self::InitialCatalog => "initial catalog",
+        }
+    }
+}
+
+/// Possible values of the `ApplicationIntent` parameter (read-only routing)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplicationIntent {
+    /// The application performs read and write operations (default)
+    ReadWrite,
+    /// The application only reads (allows routing to readable secondary replicas)
+    ReadOnly,
+}
+
+impl Display for ApplicationIntent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReadWrite => write!(f, "ReadWrite"),
+            Self::ReadOnly => write!(f, "ReadOnly"),
+        }
+    }
+}
+
 /// Struct representing a `Microsoft SQL Server` connection string
 ///
 /// All parameter values will be automatically escaped to match the required format
-#[derive(Debug)]
+///
+/// Two builders are equal if they are structurally equal (parameter order is ignored).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SqlServerConnectionString {
-    parameter_list: HashMap<String, String>,
+    parameter_list: BTreeMap<String, String>,
+    trailing_semicolon: bool,
+    database_key_style: DatabaseKeyStyle,
 }
 
 impl Default for SqlServerConnectionString {
-    #[must_use]
     fn default() -> Self {
         Self::new()
     }
@@ -43,7 +105,9 @@ impl SqlServerConnectionString {
     #[must_use]
     pub fn new() -> Self {
         SqlServerConnectionString {
-            parameter_list: HashMap::new(),
+            parameter_list: BTreeMap::new(),
+            trailing_semicolon: false,
+            database_key_style: DatabaseKeyStyle::Database,
         }
     }
 
@@ -51,6 +115,9 @@ impl SqlServerConnectionString {
     ///
     /// Automatically escapes all values to match the format required by SQL server
     ///
+    /// Keys are case-insensitive: a parameter which has already been set with a different casing
+    /// is replaced (the casing of the latest call is used).
+    ///
     /// # Examples
     /// ```rust
     /// use connection_string_generator::sqlserver::SqlServerConnectionString;
@@ -59,8 +126,61 @@ impl SqlServerConnectionString {
     /// ```
     #[must_use]
     pub fn dangerously_set_parameter(mut self, key: &str, value: &str) -> Self {
+        self.remove_parameter(key);
         self.parameter_list
-            .insert(key.to_string(), simple_encode(value));
+            .insert(key.to_string(), simple_key_value_encode(value));
+        self
+    }
+
+    /// Like [`SqlServerConnectionString::dangerously_set_parameter`] but refuses to override
+    /// a parameter which has already been set by a typed setter (e.g. `timeout`)
+    ///
+    /// Keys are compared case-insensitively, as SQL Server does.
+    ///
+    /// # Errors
+    /// Returns a [`DuplicateKeyError`] if the key is managed by a typed setter and already set
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new().set_connect_timeout(30);
+    ///
+    /// assert!(conn_string.dangerously_set_parameter_checked("Timeout", "60").is_err());
+    /// ```
+    pub fn dangerously_set_parameter_checked(
+        self,
+        key: &str,
+        value: &str,
+    ) -> Result<Self, DuplicateKeyError> {
+        let is_typed = TYPED_PARAMETERS
+            .iter()
+            .any(|typed_key| typed_key.eq_ignore_ascii_case(key));
+        if is_typed && self.get_parameter(key).is_some() {
+            return Err(DuplicateKeyError {
+                key: key.to_string(),
+            });
+        }
+
+        Ok(self.dangerously_set_parameter(key, value))
+    }
+
+    /// Removes a parameter regardless of the casing of its key (no-op if it isn't set)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new()
+    ///     .set_host_with_default_port("localhost")
+    ///     .set_connect_timeout(30)
+    ///     .unset_parameter("timeout");
+    ///
+    /// assert_eq!(conn_string.to_string(), "server=localhost");
+    /// ```
+    #[must_use]
+    pub fn unset_parameter(mut self, key: &str) -> Self {
+        self.remove_parameter(key);
         self
     }
 
@@ -79,7 +199,7 @@ impl SqlServerConnectionString {
         let mut connection_string = self.dangerously_set_parameter("user", username);
 
         // Remove password parameter if it previously has been set
-        connection_string.parameter_list.remove("password");
+        connection_string.remove_parameter("password");
 
         connection_string
     }
@@ -100,6 +220,63 @@ impl SqlServerConnectionString {
             .dangerously_set_parameter("password", password)
     }
 
+    /// Sets/Replaces the username and the password from a `(username, password)` tuple
+    ///
+    /// Parameters: `user=<username>;password=<password>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new()
+    ///     .set_credentials_tuple((String::from("user"), String::from("password")));
+    /// ```
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set_credentials_tuple(self, (username, password): (String, String)) -> Self {
+        self.set_username_and_password(&username, &password)
+    }
+
+    /// Sets/Replaces the username and the password from a [`UsernamePassword`]
+    ///
+    /// Parameters: `user=<username>;password=<password>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    /// use connection_string_generator::UsernamePassword;
+    ///
+    /// let credentials = UsernamePassword::new("user", "password");
+    ///
+    /// SqlServerConnectionString::new().set_credentials(&credentials);
+    /// ```
+    #[must_use]
+    pub fn set_credentials(self, credentials: &UsernamePassword) -> Self {
+        self.set_username_and_password(credentials.username(), credentials.password())
+    }
+
+    /// Sets/Replaces the username and the password from an optional `(username, password)` tuple
+    ///
+    /// `None` leaves the current credentials untouched.
+    ///
+    /// Parameters: `user=<username>;password=<password>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new()
+    ///     .set_credentials_opt_tuple(Some((String::from("user"), String::from("password"))))
+    ///     .set_credentials_opt_tuple(None);
+    /// ```
+    #[must_use]
+    pub fn set_credentials_opt_tuple(self, credentials: Option<(String, String)>) -> Self {
+        match credentials {
+            Some(credentials) => self.set_credentials_tuple(credentials),
+            None => self,
+        }
+    }
+
     /// Sets/Replaces the host and omits the port in the connection string
     /// (this usually results in the usage of the default port)
     ///
@@ -118,6 +295,10 @@ impl SqlServerConnectionString {
 
     /// Sets/Replaces the host and the port
     ///
+    /// If the host already contains a port (`<host>,<port>`), that port is replaced by the given one.
+    /// The port isn't validated. Use [`SqlServerConnectionString::try_set_host_with_port`]
+    /// to reject ports outside of the valid TCP port range.
+    ///
     /// Parameters: `server=<host>,<port>`
     ///
     /// # Examples
@@ -128,9 +309,97 @@ impl SqlServerConnectionString {
     /// ```
     #[must_use]
     pub fn set_host_with_port(self, host: &str, port: usize) -> Self {
+        // Strip a redundant port of a pre-formatted host
+        let host = host.split_once(',').map_or(host, |(host, _port)| host);
+
         self.dangerously_set_parameter("server", &format!("{host},{port}"))
     }
 
+    /// Sets/Replaces the host and the port from a [`HostPort`]
+    ///
+    /// Parameters: `server=<host>,<port>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    /// use connection_string_generator::HostPort;
+    ///
+    /// let host_port = HostPort::new("localhost", 1433);
+    ///
+    /// SqlServerConnectionString::new().set_host_port(&host_port);
+    /// ```
+    #[must_use]
+    pub fn set_host_port(self, host_port: &HostPort) -> Self {
+        self.set_host_with_port(host_port.host(), usize::from(host_port.port()))
+    }
+
+    /// Sets/Replaces the host and the port if the port is within the valid TCP port range (`1..=65535`)
+    ///
+    /// Parameters: `server=<host>,<port>`
+    ///
+    /// # Errors
+    /// Returns a [`PortError`] if the port is `0` or greater than `65535`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new()
+    ///     .try_set_host_with_port("localhost", 1433)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(conn_string.to_string(), "server=localhost,1433");
+    /// assert!(SqlServerConnectionString::new()
+    ///     .try_set_host_with_port("localhost", 0)
+    ///     .is_err());
+    /// ```
+    pub fn try_set_host_with_port(self, host: &str, port: usize) -> Result<Self, PortError> {
+        PortError::check(port)?;
+
+        Ok(self.set_host_with_port(host, port))
+    }
+
+    /// Sets/Replaces the host and the name of the instance (named instance)
+    ///
+    /// The value is quoted like every other value, e.g. if the instance name contains a `;`.
+    ///
+    /// Parameters: `server=<host>\<instance>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new()
+    ///     .set_host_with_named_instance("localhost", "SQLEXPRESS");
+    ///
+    /// assert_eq!(conn_string.to_string(), "server=localhost\\SQLEXPRESS");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_named_instance(self, host: &str, instance: &str) -> Self {
+        self.dangerously_set_parameter("server", &format!("{host}\\{instance}"))
+    }
+
+    /// Sets/Replaces the host and optionally the port
+    ///
+    /// Uses [`SqlServerConnectionString::set_host_with_port`] if a port is given
+    /// and [`SqlServerConnectionString::set_host_with_default_port`] otherwise.
+    ///
+    /// Parameters: `server=<host>[,<port>]`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new().set_host("localhost", Some(1433));
+    /// ```
+    #[must_use]
+    pub fn set_host(self, host: &str, port: Option<u16>) -> Self {
+        match port {
+            Some(port) => self.set_host_with_port(host, usize::from(port)),
+            None => self.set_host_with_default_port(host),
+        }
+    }
+
     /// Enables encryption
     ///
     /// Parameters: `encrypt=true`
@@ -163,9 +432,95 @@ impl SqlServerConnectionString {
             .dangerously_set_parameter("trustServerCertificate", "true")
     }
 
+    /// Enables Multiple Active Result Sets (MARS)
+    ///
+    /// Parameters: `MultipleActiveResultSets=true`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new().enable_mars();
+    /// ```
+    #[must_use]
+    pub fn enable_mars(self) -> Self {
+        self.dangerously_set_parameter("MultipleActiveResultSets", "true")
+    }
+
+    /// Sets/Replaces the application intent
+    ///
+    /// Parameters: `ApplicationIntent=<application_intent>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::{ApplicationIntent, SqlServerConnectionString};
+    ///
+    /// SqlServerConnectionString::new().set_application_intent(ApplicationIntent::ReadOnly);
+    /// ```
+    #[must_use]
+    pub fn set_application_intent(self, application_intent: ApplicationIntent) -> Self {
+        self.dangerously_set_parameter("ApplicationIntent", &application_intent.to_string())
+    }
+
+    /// Checks whether no parameter has been set yet (the connection string would be empty)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// assert!(SqlServerConnectionString::new().is_empty());
+    /// assert!(!SqlServerConnectionString::new().set_database_name("db_name").is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parameter_list.is_empty()
+    }
+
+    /// Checks whether the connection is guaranteed to be encrypted
+    ///
+    /// Returns `true` if `encrypt` is set to `true`, `yes`, `mandatory` or `strict` (case-insensitive).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// assert!(SqlServerConnectionString::new().enable_encryption().is_secure());
+    /// assert!(!SqlServerConnectionString::new().is_secure());
+    /// ```
+    #[must_use]
+    pub fn is_secure(&self) -> bool {
+        self.parameter_list.iter().any(|(key, value)| {
+            key.eq_ignore_ascii_case("encrypt")
+                && ["true", "yes", "mandatory", "strict"]
+                    .iter()
+                    .any(|secure_value| value.eq_ignore_ascii_case(secure_value))
+        })
+    }
+
+    /// Returns the stored (escaped) value of a parameter (the key is case-insensitive)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new().set_connect_timeout(30);
+    ///
+    /// assert_eq!(conn_string.get_parameter("timeout"), Some("30"));
+    /// assert_eq!(conn_string.get_parameter("database"), None);
+    /// ```
+    #[must_use]
+    pub fn get_parameter(&self, key: &str) -> Option<&str> {
+        self.parameter_list
+            .iter()
+            .find(|(existing_key, _)| existing_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
     /// Sets/Replaces the database name
     ///
-    /// Parameters: `database=<db_name>`
+    /// The spelling of the key can be chosen with [`SqlServerConnectionString::set_database_key_style`].
+    ///
+    /// Parameters: `database=<db_name>` or `initial catalog=<db_name>`
     ///
     /// # Examples
     /// ```rust
@@ -175,12 +530,55 @@ impl SqlServerConnectionString {
     /// ```
     #[must_use]
     pub fn set_database_name(self, db_name: &str) -> Self {
-        self.dangerously_set_parameter("database", db_name)
+        let key = self.database_key_style.key();
+        self.dangerously_set_parameter(key, db_name)
+    }
+
+    /// Alias for [`SqlServerConnectionString::set_database_name`]
+    ///
+    /// Parameters: `database=<db_name>` or `initial catalog=<db_name>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new().set_initial_catalog("db_name");
+    /// ```
+    #[must_use]
+    pub fn set_initial_catalog(self, db_name: &str) -> Self {
+        self.set_database_name(db_name)
+    }
+
+    /// Sets the spelling of the database parameter (default: [`DatabaseKeyStyle::Database`])
+    ///
+    /// An already set database name is moved to the new key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::{DatabaseKeyStyle, SqlServerConnectionString};
+    ///
+    /// let conn_string = SqlServerConnectionString::new()
+    ///     .set_database_name("db_name")
+    ///     .set_database_key_style(DatabaseKeyStyle::InitialCatalog);
+    ///
+    /// assert_eq!(conn_string.to_string(), "initial catalog=db_name");
+    /// ```
+    #[must_use]
+    pub fn set_database_key_style(mut self, database_key_style: DatabaseKeyStyle) -> Self {
+        let previous_key = self.database_key_style.key();
+        self.database_key_style = database_key_style;
+
+        if let Some(db_name) = self.remove_parameter(previous_key) {
+            self.parameter_list
+                .insert(database_key_style.key().to_string(), db_name);
+        }
+        self
     }
 
     /// Sets/Replaces the connect timeout (in seconds)
     ///
-    /// If the provided value is negative, the action will be ignored
+    /// If the provided value is negative, the action will be ignored.
+    /// The upper bound is `i32::MAX` as this is the largest value accepted by the SQL Server drivers.
     ///
     /// Parameters: `timeout=<connect_timeout>`
     ///
@@ -199,6 +597,40 @@ impl SqlServerConnectionString {
         self.dangerously_set_parameter("timeout", &connect_timeout.to_string())
     }
 
+    /// Sets/Replaces the connect timeout (in seconds) from an unsigned value
+    ///
+    /// Values above `i32::MAX` are clamped to `i32::MAX`.
+    ///
+    /// Parameters: `timeout=<connect_timeout>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new().set_connect_timeout_u32(u32::MAX);
+    ///
+    /// assert_eq!(conn_string.to_string(), "timeout=2147483647");
+    /// ```
+    #[must_use]
+    pub fn set_connect_timeout_u32(self, connect_timeout: u32) -> Self {
+        self.set_connect_timeout(i32::try_from(connect_timeout).unwrap_or(i32::MAX))
+    }
+
+    /// Alias for [`SqlServerConnectionString::set_connect_timeout`]
+    ///
+    /// Parameters: `timeout=<connect_timeout>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new().set_connection_timeout(30);
+    /// ```
+    #[must_use]
+    pub fn set_connection_timeout(self, connect_timeout: i32) -> Self {
+        self.set_connect_timeout(connect_timeout)
+    }
+
     /// Sets/Replaces the command timeout (in seconds)
     ///
     /// If the provided value is negative, the action will be ignored
@@ -219,6 +651,24 @@ impl SqlServerConnectionString {
         self.dangerously_set_parameter("command timeout", &command_timeout.to_string())
     }
 
+    /// Sets/Replaces the connect timeout and the command timeout (in seconds) in one call
+    ///
+    /// See [`SqlServerConnectionString::set_connect_timeout`] and [`SqlServerConnectionString::set_command_timeout`].
+    ///
+    /// Parameters: `timeout=<connect_secs>;command timeout=<command_secs>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// SqlServerConnectionString::new().set_all_timeouts(15, 30);
+    /// ```
+    #[must_use]
+    pub fn set_all_timeouts(self, connect_secs: i32, command_secs: i32) -> Self {
+        self.set_connect_timeout(connect_secs)
+            .set_command_timeout(command_secs)
+    }
+
     /// Sets/Replaces the connection retry count
     ///
     /// Parameters: `connectRetryCount=<connect_retry_count>`
@@ -238,7 +688,11 @@ impl SqlServerConnectionString {
     ///
     /// Allowed values: 1..=60 . The provided value will be increased/decreased to fit this range
     ///
-    /// Parameters: `connectRetryInterval=<connect_retry_interval>`
+    /// If no retry count has been set before, the retry count is set to `1` as well
+    /// to make the intention (retrying once) explicit instead of relying on the default of the driver.
+    /// A retry count set afterwards replaces it.
+    ///
+    /// Parameters: `connectRetryInterval=<connect_retry_interval>[;connectRetryCount=1]`
     ///
     /// # Examples
     /// ```rust
@@ -249,115 +703,245 @@ impl SqlServerConnectionString {
     #[must_use]
     pub fn set_connect_retry_interval(self, connect_retry_interval: u8) -> Self {
         // Clip to range 1..=60
-        let connect_retry_interval = min(max(1, connect_retry_interval), 60);
+        let connect_retry_interval = connect_retry_interval.clamp(1, 60);
+
+        let connection_string = if self.get_parameter("connectRetryCount").is_some() {
+            self
+        } else {
+            self.set_connect_retry_count(1)
+        };
 
-        self.dangerously_set_parameter("connectRetryInterval", &connect_retry_interval.to_string())
+        connection_string
+            .dangerously_set_parameter("connectRetryInterval", &connect_retry_interval.to_string())
     }
-}
 
-impl Display for SqlServerConnectionString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let conn_string = self
-            .parameter_list
-            .iter()
-            .map(|(key, value)| format!("{key}={value}"))
-            .collect::<Vec<String>>()
-            .join(";");
+    /// Sets whether a `;` should be appended after the last parameter (default: `false`)
+    ///
+    /// Some consumers of connection strings expect every parameter to be terminated by a `;`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let conn_string = SqlServerConnectionString::new()
+    ///     .set_database_name("db_name")
+    ///     .with_trailing_semicolon(true);
+    ///
+    /// assert_eq!(conn_string.to_string(), "database=db_name;");
+    /// ```
+    #[must_use]
+    pub fn with_trailing_semicolon(mut self, trailing_semicolon: bool) -> Self {
+        self.trailing_semicolon = trailing_semicolon;
+        self
+    }
 
-        write!(f, "{conn_string}")
+    /// Renders the connection string exactly like [`Display`] but with the password masked (`password=***`)
+    ///
+    /// This is meant for logging.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let redacted = SqlServerConnectionString::new()
+    ///     .set_username_and_password("user", "password")
+    ///     .set_host_with_port("localhost", 1433)
+    ///     .to_redacted_string();
+    ///
+    /// assert_eq!(redacted, "password=***;server=localhost,1433;user=user");
+    /// ```
+    #[must_use]
+    pub fn to_redacted_string(&self) -> String {
+        self.render(true)
     }
-}
 
-/// Simple encoding for values in a SQL server connection string
-///
-/// According to [Microsoft](https://learn.microsoft.com/en-us/sql/connect/ado-net/connection-strings?view=sql-server-ver16)
-/// (Accessed: 2024-08-20):
-/// > If a value contains the semicolon, Unicode control characters,
-/// > or leading or trailing white space, it must be enclosed in single or double quotation marks
-///
-/// > The enclosing character may not occur within the value it encloses.
-/// > Therefore, a value containing single quotation marks can be enclosed only in double quotation marks, and vice versa
-///
-/// > You can also escape the enclosing character by using two of them together
-///
-/// This function checks if quotation marks are needed and only adds them if they are required.
-///
-/// Double quotation marks are preferred:
-///   - If the string only contains single or double quotation marks, the other type will be used for enclosing the string
-///   - If both types are present, the double quotation marks will be escaped (replaced by `""`)
-///     and double quotation marks will be used to enclose the string
-fn simple_encode(s: &str) -> String {
-    let quotes_needed =
-        str_includes_control_char(s) || s.starts_with(' ') || s.ends_with(' ') || s.contains(';');
+    /// Renders the parameters across multiple labeled lines with the password masked
+    ///
+    /// `user`, `password` and `server` (as `host`) are listed first, followed by all other parameters.
+    /// This is meant for human-readable diagnostic output and isn't a valid connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let pretty = SqlServerConnectionString::new()
+    ///     .set_username_and_password("user", "password")
+    ///     .set_host_with_port("localhost", 1433)
+    ///     .set_database_name("db_name")
+    ///     .to_pretty_string();
+    ///
+    /// assert_eq!(
+    ///     pretty,
+    ///     "user: user\npassword: ***\nhost: localhost,1433\nparams:\n  database: db_name\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_pretty_string(&self) -> String {
+        let mut pretty = String::new();
+
+        let has_password = self.parameter_list.keys().any(|key| is_password_key(key));
+
+        for (label, value) in [
+            ("user", self.get_parameter("user")),
+            ("password", has_password.then_some(MASKED_PASSWORD)),
+            ("host", self.get_parameter("server")),
+        ] {
+            if let Some(value) = value {
+                let _ = writeln!(pretty, "{label}: {value}");
+            }
+        }
 
-    if !quotes_needed {
-        return s.to_string();
-    }
+        let mut params = self
+            .parameter_list
+            .iter()
+            .filter(|(key, _)| {
+                !(key.eq_ignore_ascii_case("user")
+                    || key.eq_ignore_ascii_case("server")
+                    || is_password_key(key))
+            })
+            .peekable();
+
+        if params.peek().is_some() {
+            pretty.push_str("params:\n");
+        }
 
-    let includes_double_quotation = s.contains('"');
-    let includes_single_quotation = s.contains('\'');
+        for (key, value) in params {
+            let _ = writeln!(pretty, "  {key}: {value}");
+        }
 
-    if !includes_double_quotation {
-        return format!("\"{s}\"");
+        pretty
     }
 
-    if !includes_single_quotation {
-        return format!("'{s}'");
+    /// Exports the parameters as JSON object (sorted by key)
+    ///
+    /// **Attention:** The output contains the password in plain text.
+    /// Use [`SqlServerConnectionString::to_masked_json`] for logging.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let json = SqlServerConnectionString::new()
+    ///     .set_username_and_password("user", "password")
+    ///     .to_json();
+    ///
+    /// assert_eq!(json, r#"{"password":"password","user":"user"}"#);
+    /// ```
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        self.json_object(false).to_string()
     }
 
-    let s = s.replace('"', "\"\"");
-
-    format!("\"{s}\"")
-}
+    /// Exports the parameters as JSON object (sorted by key) with the value of `password` masked
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sqlserver::SqlServerConnectionString;
+    ///
+    /// let json = SqlServerConnectionString::new()
+    ///     .set_username_and_password("user", "password")
+    ///     .to_masked_json();
+    ///
+    /// assert_eq!(json, r#"{"password":"***","user":"user"}"#);
+    /// ```
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_masked_json(&self) -> String {
+        self.json_object(true).to_string()
+    }
 
-/// Checks if the given &str contains a control character by using [`char::is_control`]
-fn str_includes_control_char(s: &str) -> bool {
-    s.chars().any(char::is_control)
+    /// Collects the parameters into a JSON object and optionally masks the password
+    #[cfg(feature = "serde")]
+    fn json_object(&self, mask_password: bool) -> serde_json::Value {
+        let object = self
+            .parameter_list
+            .iter()
+            .map(|(key, value)| {
+                let value = if mask_password && is_password_key(key) {
+                    String::from(MASKED_PASSWORD)
+                } else {
+                    value.clone()
+                };
+                (key.clone(), serde_json::Value::String(value))
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        serde_json::Value::Object(object)
+    }
 }
 
-#[cfg(test)]
-mod test {
-    use crate::sqlserver::simple_encode;
+impl SqlServerConnectionString {
+    /// Removes a parameter regardless of the casing of its key and returns its value
+    fn remove_parameter(&mut self, key: &str) -> Option<String> {
+        let existing_key = self
+            .parameter_list
+            .keys()
+            .find(|existing_key| existing_key.eq_ignore_ascii_case(key))?
+            .clone();
 
-    use super::SqlServerConnectionString;
+        self.parameter_list.remove(&existing_key)
+    }
 
-    /// Test functionality of [`simple_encode`]
-    #[test]
-    fn test_simple_encode() {
-        // No changes
-        assert_eq!(&simple_encode("a"), "a");
-        assert_eq!(&simple_encode("a a"), "a a");
-        assert_eq!(&simple_encode("a \"a"), "a \"a");
-        assert_eq!(&simple_encode("a' a"), "a' a");
-        assert_eq!(&simple_encode("a' \"a"), "a' \"a");
+    /// Renders the connection string and optionally masks the password
+    fn render(&self, mask_password: bool) -> String {
+        let mut conn_string = self
+            .parameter_list
+            .iter()
+            .map(|(key, value)| {
+                if mask_password && is_password_key(key) {
+                    format!("{key}={MASKED_PASSWORD}")
+                } else {
+                    format!("{key}={value}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(";");
 
-        // Leading/trailing spaces
-        assert_eq!(&simple_encode(" a"), "\" a\"");
-        assert_eq!(&simple_encode("a "), "\"a \"");
-        assert_eq!(&simple_encode(" a "), "\" a \"");
-        assert_eq!(&simple_encode("🥙"), "🥙");
-        assert_eq!(&simple_encode("🥙 "), "\"🥙 \"");
+        if self.trailing_semicolon && !self.parameter_list.is_empty() {
+            conn_string.push(';');
+        }
 
-        // Semicolon
-        assert_eq!(&simple_encode("a;a"), "\"a;a\"");
-        assert_eq!(&simple_encode(" a;a"), "\" a;a\"");
-        assert_eq!(&simple_encode("a;a "), "\"a;a \"");
-        assert_eq!(&simple_encode(" a;a "), "\" a;a \"");
+        conn_string
+    }
+}
 
-        // Control characters
-        assert_eq!(&simple_encode("\0"), "\"\0\"");
-        assert_eq!(&simple_encode("a\0a"), "\"a\0a\"");
+impl Display for SqlServerConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
 
-        // Includes single quotation mark
-        assert_eq!(&simple_encode(" a'a"), "\" a'a\"");
+/// Compares the rendered connection string with the given string
+///
+/// The parameters are always rendered sorted by key.
+impl PartialEq<str> for SqlServerConnectionString {
+    fn eq(&self, other: &str) -> bool {
+        self.to_string().as_str() == other
+    }
+}
 
-        // Includes double quotation mark
-        assert_eq!(&simple_encode(" a\"a"), "' a\"a'");
+impl PartialEq<&str> for SqlServerConnectionString {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq(*other)
+    }
+}
 
-        // Includes both quotation marks
-        assert_eq!(&simple_encode(" 'a\"a"), "\" 'a\"\"a\"");
-        assert_eq!(&simple_encode(" 'a\"\"a"), "\" 'a\"\"\"\"a\"");
+impl PartialEq<String> for SqlServerConnectionString {
+    fn eq(&self, other: &String) -> bool {
+        self.eq(other.as_str())
     }
+}
+
+/// Checks if the key holds the password (case-insensitive, including the ADO.NET synonym `pwd`)
+fn is_password_key(key: &str) -> bool {
+    key.eq_ignore_ascii_case("password") || key.eq_ignore_ascii_case("pwd")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ApplicationIntent, DatabaseKeyStyle, SqlServerConnectionString};
+    use crate::{HostPort, UsernamePassword};
 
     /// Test empty/default config
     #[test]
@@ -366,6 +950,16 @@ mod test {
         assert_eq!(&conn_string.to_string(), "");
     }
 
+    /// Test comparing against the rendered string
+    #[test]
+    fn test_partial_eq_str() {
+        let conn_string = SqlServerConnectionString::new().set_database_name("DbName");
+
+        assert_eq!(conn_string, "database=DbName");
+        assert_eq!(conn_string, String::from("database=DbName"));
+        assert_ne!(conn_string, "database=Other");
+    }
+
     /// Test functionality of [`SqlServerConnectionString::dangerously_set_parameter`]
     #[test]
     fn test_dangerously_set_parameter() {
@@ -389,17 +983,35 @@ mod test {
 
         // Set username and password
         let conn_string = conn_string.set_username_and_password("User1", "Pwd");
-        let conn_string_as_string = conn_string.to_string();
-        assert!(
-            &conn_string_as_string == "user=User1;password=Pwd"
-                || &conn_string_as_string == "password=Pwd;user=User1"
-        );
+        assert_eq!(&conn_string.to_string(), "password=Pwd;user=User1");
 
         // Replace username and implicitly delete password
         let conn_string = conn_string.set_username_without_password("User2");
         assert_eq!(&conn_string.to_string(), "user=User2");
     }
 
+    /// Test credentials from (optional) tuples
+    #[test]
+    fn test_set_credentials_tuple() {
+        let credentials = (String::from("User"), String::from("Pwd"));
+
+        let conn_string = SqlServerConnectionString::new()
+            .set_credentials_tuple(credentials.clone())
+            .to_string();
+        assert_eq!(conn_string, "password=Pwd;user=User");
+
+        let conn_string = SqlServerConnectionString::new()
+            .set_credentials_opt_tuple(Some(credentials))
+            .to_string();
+        assert_eq!(conn_string, "password=Pwd;user=User");
+
+        // None => no-op
+        let conn_string = SqlServerConnectionString::new()
+            .set_database_name("DbName")
+            .set_credentials_opt_tuple(None);
+        assert_eq!(&conn_string.to_string(), "database=DbName");
+    }
+
     /// Test setting host config (host, host&port)
     #[test]
     fn test_set_host() {
@@ -415,6 +1027,25 @@ mod test {
         assert_eq!(&conn_string.to_string(), "server=Host2");
     }
 
+    /// Test setting the port for a host which already contains a port
+    #[test]
+    fn test_set_host_with_port_preformatted_host() {
+        let conn_string = SqlServerConnectionString::new().set_host_with_port("Host,1433", 5432);
+        assert_eq!(&conn_string.to_string(), "server=Host,5432");
+    }
+
+    /// Test setting the host with an optional port
+    #[test]
+    fn test_set_host_with_optional_port() {
+        let conn_string = SqlServerConnectionString::new();
+
+        let conn_string = conn_string.set_host("Host", Some(80));
+        assert_eq!(&conn_string.to_string(), "server=Host,80");
+
+        let conn_string = conn_string.set_host("Host", None);
+        assert_eq!(&conn_string.to_string(), "server=Host");
+    }
+
     /// Test enabling encryption
     #[test]
     fn test_enable_encryption() {
@@ -429,13 +1060,43 @@ mod test {
         let conn_string =
             SqlServerConnectionString::new().enable_encryption_and_trust_server_certificate();
 
-        let conn_string_as_string = conn_string.to_string();
-        assert!(
-            &conn_string_as_string == "encrypt=true;trustServerCertificate=true"
-                || &conn_string_as_string == "trustServerCertificate=true;encrypt=true"
+        assert_eq!(
+            &conn_string.to_string(),
+            "encrypt=true;trustServerCertificate=true"
         );
     }
 
+    /// Test reading back parameters
+    #[test]
+    fn test_get_parameter() {
+        let conn_string = SqlServerConnectionString::new();
+        assert_eq!(conn_string.get_parameter("timeout"), None);
+
+        let conn_string = conn_string
+            .set_connect_timeout(30)
+            .set_database_name(" DbName");
+        assert_eq!(conn_string.get_parameter("timeout"), Some("30"));
+        assert_eq!(conn_string.get_parameter("database"), Some("\" DbName\""));
+        assert_eq!(conn_string.get_parameter("command timeout"), None);
+    }
+
+    /// Test detection of secure configurations
+    #[test]
+    fn test_is_secure() {
+        let conn_string = SqlServerConnectionString::new();
+        assert!(!conn_string.is_secure());
+
+        let conn_string = conn_string.dangerously_set_parameter("encrypt", "false");
+        assert!(!conn_string.is_secure());
+
+        let conn_string = conn_string.enable_encryption();
+        assert!(conn_string.is_secure());
+
+        let conn_string =
+            SqlServerConnectionString::new().dangerously_set_parameter("Encrypt", "Strict");
+        assert!(conn_string.is_secure());
+    }
+
     /// Test database name
     #[test]
     fn test_set_database_name() {
@@ -444,6 +1105,216 @@ mod test {
         assert_eq!(&conn_string.to_string(), "database=DbName");
     }
 
+    /// Test both spellings of the database parameter
+    #[test]
+    fn test_set_database_key_style() {
+        let conn_string = SqlServerConnectionString::new().set_initial_catalog("DbName");
+        assert_eq!(&conn_string.to_string(), "database=DbName");
+
+        let conn_string = conn_string.set_database_key_style(DatabaseKeyStyle::InitialCatalog);
+        assert_eq!(&conn_string.to_string(), "initial catalog=DbName");
+
+        let conn_string = conn_string.set_database_name("Other");
+        assert_eq!(&conn_string.to_string(), "initial catalog=Other");
+
+        let conn_string = conn_string.set_database_key_style(DatabaseKeyStyle::Database);
+        assert_eq!(&conn_string.to_string(), "database=Other");
+    }
+
+    /// Test that only the password is masked in the redacted string
+    #[test]
+    fn test_to_redacted_string() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_username_and_password("User", "Secret")
+            .set_host_with_port("Host", 1433)
+            .set_database_name("DbName")
+            .set_connect_timeout(30)
+            .with_trailing_semicolon(true);
+        let redacted = conn_string.to_redacted_string();
+        assert_eq!(
+            redacted,
+            "database=DbName;password=***;server=Host,1433;timeout=30;user=User;"
+        );
+        assert!(!redacted.contains("Secret"));
+
+        // Nothing to redact
+        let conn_string = SqlServerConnectionString::new().set_username_without_password("User");
+        assert_eq!(conn_string.to_redacted_string(), conn_string.to_string());
+
+        // Differently-cased keys and the `pwd` synonym
+        let conn_string = SqlServerConnectionString::new()
+            .dangerously_set_parameter("Password", "Secret")
+            .dangerously_set_parameter("PWD", "Secret");
+        assert_eq!(conn_string.to_redacted_string(), "PWD=***;Password=***");
+    }
+
+    /// Test deriving variants from a cloned base configuration
+    #[test]
+    fn test_clone() {
+        let base = SqlServerConnectionString::new()
+            .set_host_with_port("Host", 1433)
+            .set_database_name("Base");
+
+        let variant = base.clone().set_database_name("Variant");
+
+        assert_eq!(&base.to_string(), "database=Base;server=Host,1433");
+        assert_eq!(&variant.to_string(), "database=Variant;server=Host,1433");
+    }
+
+    /// Test that the order of the setter calls doesn't matter for equality
+    #[test]
+    fn test_eq() {
+        let a = SqlServerConnectionString::new()
+            .set_username_and_password("User", "Password")
+            .set_host_with_port("Host", 1433)
+            .set_database_name("DbName");
+        let b = SqlServerConnectionString::new()
+            .set_database_name("DbName")
+            .set_host_with_port("Host", 1433)
+            .set_username_and_password("User", "Password");
+        assert_eq!(a, b);
+
+        let b = b.set_database_name("Other");
+        assert_ne!(a, b);
+    }
+
+    /// Test MARS and the application intent
+    #[test]
+    fn test_mars_and_application_intent() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_host_with_default_port("Host")
+            .enable_mars()
+            .set_application_intent(ApplicationIntent::ReadWrite);
+        assert_eq!(
+            &conn_string.to_string(),
+            "ApplicationIntent=ReadWrite;MultipleActiveResultSets=true;server=Host"
+        );
+
+        let conn_string = conn_string.set_application_intent(ApplicationIntent::ReadOnly);
+        assert_eq!(
+            &conn_string.to_string(),
+            "ApplicationIntent=ReadOnly;MultipleActiveResultSets=true;server=Host"
+        );
+    }
+
+    /// Test the setters accepting the public [`UsernamePassword`] and [`HostPort`] structs
+    #[test]
+    fn test_set_credentials_and_host_port() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_credentials(&UsernamePassword::new("User", "Pass;word"))
+            .set_host_port(&HostPort::new("Host", 1433));
+        assert_eq!(
+            &conn_string.to_string(),
+            "password=\"Pass;word\";server=Host,1433;user=User"
+        );
+    }
+
+    /// Test the empty check
+    #[test]
+    fn test_is_empty() {
+        let conn_string = SqlServerConnectionString::new();
+        assert!(conn_string.is_empty());
+
+        let conn_string = conn_string.set_host_with_default_port("Host");
+        assert!(!conn_string.is_empty());
+
+        // Removing the only parameter results in an empty connection string again
+        let conn_string = conn_string.unset_parameter("server");
+        assert!(conn_string.is_empty());
+    }
+
+    /// Test that keys are case-insensitive
+    #[test]
+    fn test_case_insensitive_keys() {
+        let conn_string = SqlServerConnectionString::new()
+            .dangerously_set_parameter("Server", "Host1")
+            .dangerously_set_parameter("server", "Host2");
+        assert_eq!(&conn_string.to_string(), "server=Host2");
+        assert_eq!(conn_string.get_parameter("SERVER"), Some("Host2"));
+
+        let conn_string = conn_string
+            .dangerously_set_parameter("Password", "secret")
+            .set_username_without_password("User");
+        assert_eq!(&conn_string.to_string(), "server=Host2;user=User");
+
+        let conn_string = conn_string.unset_parameter("USER");
+        assert_eq!(&conn_string.to_string(), "server=Host2");
+    }
+
+    /// Test removing parameters
+    #[test]
+    fn test_unset_parameter() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_host_with_default_port("Host")
+            .dangerously_set_parameter("Application Name", "App");
+        assert_eq!(&conn_string.to_string(), "Application Name=App;server=Host");
+
+        let conn_string = conn_string
+            .unset_parameter("Application Name")
+            .unset_parameter("not set");
+        assert_eq!(&conn_string.to_string(), "server=Host");
+    }
+
+    /// Test that the checked setter refuses to override parameters of typed setters
+    #[test]
+    fn test_dangerously_set_parameter_checked() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_connect_timeout(30)
+            .set_database_name("DbName");
+
+        assert!(conn_string
+            .clone()
+            .dangerously_set_parameter_checked("timeout", "60")
+            .is_err());
+        assert!(conn_string
+            .clone()
+            .dangerously_set_parameter_checked("Database", "Other")
+            .is_err());
+
+        let conn_string = conn_string
+            .dangerously_set_parameter_checked("Application Name", "App")
+            .unwrap();
+        assert_eq!(
+            &conn_string.to_string(),
+            "Application Name=App;database=DbName;timeout=30"
+        );
+    }
+
+    /// Test named instances (and their quoting)
+    #[test]
+    fn test_set_host_with_named_instance() {
+        let conn_string =
+            SqlServerConnectionString::new().set_host_with_named_instance("Host", "SQLEXPRESS");
+        assert_eq!(&conn_string.to_string(), r"server=Host\SQLEXPRESS");
+
+        // Spaces within the value don't require quotes
+        let conn_string = conn_string.set_host_with_named_instance("Host", "My Instance");
+        assert_eq!(&conn_string.to_string(), r"server=Host\My Instance");
+
+        let conn_string = conn_string.set_host_with_named_instance("Host", "My Instance ");
+        assert_eq!(&conn_string.to_string(), r#"server="Host\My Instance ""#);
+
+        let conn_string = conn_string.set_host_with_named_instance("Host", "Inst;ance");
+        assert_eq!(&conn_string.to_string(), r#"server="Host\Inst;ance""#);
+    }
+
+    /// Test port range validation
+    #[test]
+    fn test_try_set_host_with_port() {
+        assert!(SqlServerConnectionString::new()
+            .try_set_host_with_port("Host", 0)
+            .is_err());
+
+        let conn_string = SqlServerConnectionString::new()
+            .try_set_host_with_port("Host", 65535)
+            .unwrap();
+        assert_eq!(&conn_string.to_string(), "server=Host,65535");
+
+        assert!(SqlServerConnectionString::new()
+            .try_set_host_with_port("Host", 65536)
+            .is_err());
+    }
+
     /// Test connect timeout
     #[test]
     fn test_set_connect_timeout() {
@@ -460,6 +1331,38 @@ mod test {
         // Negative value => ignored
         let conn_string = conn_string.set_connect_timeout(-2);
         assert_eq!(&conn_string.to_string(), "timeout=30");
+
+        // Maximum value
+        let conn_string = conn_string.set_connect_timeout(i32::MAX);
+        assert_eq!(&conn_string.to_string(), "timeout=2147483647");
+    }
+
+    /// Test the unsigned connect timeout (incl. clamping to `i32::MAX`)
+    #[test]
+    fn test_set_connect_timeout_u32() {
+        let conn_string = SqlServerConnectionString::new().set_connect_timeout_u32(30);
+        assert_eq!(&conn_string.to_string(), "timeout=30");
+
+        let conn_string = conn_string.set_connect_timeout_u32(i32::MAX.unsigned_abs());
+        assert_eq!(&conn_string.to_string(), "timeout=2147483647");
+
+        let conn_string = conn_string.set_connect_timeout_u32(u32::MAX);
+        assert_eq!(&conn_string.to_string(), "timeout=2147483647");
+    }
+
+    /// Test that the connection timeout alias behaves identically
+    #[test]
+    fn test_set_connection_timeout() {
+        for timeout in [-2, 0, 30] {
+            assert_eq!(
+                SqlServerConnectionString::new()
+                    .set_connection_timeout(timeout)
+                    .to_string(),
+                SqlServerConnectionString::new()
+                    .set_connect_timeout(timeout)
+                    .to_string()
+            );
+        }
     }
 
     /// Test command timeout
@@ -480,6 +1383,22 @@ mod test {
         assert_eq!(&conn_string.to_string(), "command timeout=30");
     }
 
+    /// Test setting connect and command timeout together
+    #[test]
+    fn test_set_all_timeouts() {
+        let conn_string = SqlServerConnectionString::new().set_all_timeouts(15, 30);
+
+        assert_eq!(
+            conn_string.parameter_list.get("timeout"),
+            Some(&String::from("15"))
+        );
+        assert_eq!(
+            conn_string.parameter_list.get("command timeout"),
+            Some(&String::from("30"))
+        );
+        assert_eq!(conn_string.parameter_list.len(), 2);
+    }
+
     /// Test connect retry count
     #[test]
     fn test_set_connect_retry_count() {
@@ -499,17 +1418,144 @@ mod test {
 
         // <= 0 => replaced by 1 (min value)
         let conn_string = conn_string.set_connect_retry_interval(0);
-        assert_eq!(&conn_string.to_string(), "connectRetryInterval=1");
+        assert_eq!(
+            conn_string.parameter_list.get("connectRetryInterval"),
+            Some(&String::from("1"))
+        );
 
         // Normal values
         let conn_string = conn_string.set_connect_retry_interval(1);
-        assert_eq!(&conn_string.to_string(), "connectRetryInterval=1");
+        assert_eq!(
+            conn_string.parameter_list.get("connectRetryInterval"),
+            Some(&String::from("1"))
+        );
 
         let conn_string = conn_string.set_connect_retry_interval(60);
-        assert_eq!(&conn_string.to_string(), "connectRetryInterval=60");
+        assert_eq!(
+            conn_string.parameter_list.get("connectRetryInterval"),
+            Some(&String::from("60"))
+        );
 
         // > 60 => replaced by 60 (max value)
         let conn_string = conn_string.set_connect_retry_interval(61);
-        assert_eq!(&conn_string.to_string(), "connectRetryInterval=60");
+        assert_eq!(
+            conn_string.parameter_list.get("connectRetryInterval"),
+            Some(&String::from("60"))
+        );
+    }
+
+    /// Test that setting only the retry interval also sets a retry count
+    #[test]
+    fn test_set_connect_retry_interval_sets_retry_count() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_connect_retry_interval(10)
+            .to_string();
+        assert_eq!(conn_string, "connectRetryCount=1;connectRetryInterval=10");
+
+        // An existing retry count is kept
+        let conn_string = SqlServerConnectionString::new()
+            .set_connect_retry_count(5)
+            .set_connect_retry_interval(10)
+            .to_string();
+        assert_eq!(conn_string, "connectRetryCount=5;connectRetryInterval=10");
+    }
+
+    /// Test trailing semicolon toggle
+    #[test]
+    fn test_with_trailing_semicolon() {
+        let conn_string = SqlServerConnectionString::new().set_database_name("DbName");
+        assert_eq!(&conn_string.to_string(), "database=DbName");
+
+        let conn_string = conn_string.with_trailing_semicolon(true);
+        assert_eq!(&conn_string.to_string(), "database=DbName;");
+
+        let conn_string = conn_string.with_trailing_semicolon(false);
+        assert_eq!(&conn_string.to_string(), "database=DbName");
+
+        // Nothing to terminate
+        let conn_string = SqlServerConnectionString::new().with_trailing_semicolon(true);
+        assert_eq!(&conn_string.to_string(), "");
+    }
+
+    /// Test the multi-line diagnostic output
+    #[test]
+    fn test_to_pretty_string() {
+        assert_eq!(SqlServerConnectionString::new().to_pretty_string(), "");
+
+        let pretty = SqlServerConnectionString::new()
+            .set_username_and_password("User", "Secret")
+            .set_host_with_port("Host", 1433)
+            .set_database_name("DbName")
+            .set_connect_timeout(30)
+            .to_pretty_string();
+
+        assert_eq!(
+            pretty,
+            "user: User\n\
+             password: ***\n\
+             host: Host,1433\n\
+             params:\n  \
+             database: DbName\n  \
+             timeout: 30\n"
+        );
+        assert!(!pretty.contains("Secret"));
+
+        // Differently-cased keys and the `pwd` synonym
+        let pretty = SqlServerConnectionString::new()
+            .dangerously_set_parameter("User", "User")
+            .dangerously_set_parameter("PWD", "Secret")
+            .dangerously_set_parameter("Server", "Host")
+            .to_pretty_string();
+        assert_eq!(pretty, "user: User\npassword: ***\nhost: Host\n");
+    }
+
+    /// Test JSON export with and without masking the password
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_username_and_password("User", "Secret")
+            .set_database_name("DbName");
+
+        assert_eq!(
+            conn_string.to_json(),
+            r#"{"database":"DbName","password":"Secret","user":"User"}"#
+        );
+
+        let masked = conn_string.to_masked_json();
+        assert_eq!(
+            masked,
+            r#"{"database":"DbName","password":"***","user":"User"}"#
+        );
+        assert!(!masked.contains("Secret"));
+
+        // Nothing to mask
+        let conn_string = SqlServerConnectionString::new().set_username_without_password("User");
+        assert_eq!(conn_string.to_masked_json(), r#"{"user":"User"}"#);
+
+        // Differently-cased keys and the `pwd` synonym
+        let conn_string = SqlServerConnectionString::new()
+            .dangerously_set_parameter("Password", "Secret")
+            .dangerously_set_parameter("PWD", "Secret");
+        assert_eq!(
+            conn_string.to_masked_json(),
+            r#"{"PWD":"***","Password":"***"}"#
+        );
+    }
+
+    /// Test the serde round trip of a fully-built connection string
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let conn_string = SqlServerConnectionString::new()
+            .set_username_and_password("User", "Pass;word")
+            .set_host_with_port("Host", 1433)
+            .set_database_key_style(DatabaseKeyStyle::InitialCatalog)
+            .set_database_name("DbName")
+            .with_trailing_semicolon(true);
+
+        let json = serde_json::to_string(&conn_string).unwrap();
+        let deserialized: SqlServerConnectionString = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.to_string(), conn_string.to_string());
     }
 }
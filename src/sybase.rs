@@ -0,0 +1,314 @@
+//! Connection string generator for `SAP ASE` (formerly `Sybase ASE`)
+
+use std::{fmt::Display, fmt::Write};
+
+/// Default port of an `SAP ASE` server (used for the JDBC form if no port has been set)
+const DEFAULT_PORT: usize = 5000;
+
+/// Struct representing an `SAP ASE` connection string
+///
+/// The [`Display`] implementation renders the ODBC keyword form
+/// (`DSN=...;Server=...;Port=...;Database=...;UID=...;PWD=...`).
+/// The JDBC form is available via [`SybaseConnectionString::to_jdbc_string`].
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SybaseConnectionString {
+    dsn: Option<String>,
+    host: Option<String>,
+    port: Option<usize>,
+    database: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl Default for SybaseConnectionString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SybaseConnectionString {
+    /// Creates a new and empty [`SybaseConnectionString`]
+    ///
+    /// This function initializes a new [`SybaseConnectionString`] with empty values.
+    /// Without any further changes this results in an empty string which isn't really useful.
+    ///
+    /// This function can be chained other functions to fill the missing fields in the connection string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new()
+    ///   .set_username_and_password("user", "password")
+    ///   .set_host_with_port("localhost", 5000)
+    ///   .set_database_name("db_name");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dsn: None,
+            host: None,
+            port: None,
+            database: None,
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Sets/Replaces the data source name
+    ///
+    /// Parameters: `DSN=<dsn>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new().set_dsn("my_dsn");
+    /// ```
+    #[must_use]
+    pub fn set_dsn(mut self, dsn: &str) -> Self {
+        self.dsn = Some(dsn.to_string());
+        self
+    }
+
+    /// Sets/Replaces the username and removes the password (if it has been previously set)
+    ///
+    /// Parameters: `UID=<username>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new().set_username_without_password("user");
+    /// ```
+    #[must_use]
+    pub fn set_username_without_password(mut self, username: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = None;
+        self
+    }
+
+    /// Sets/Replaces the username and the password
+    ///
+    /// Parameters: `UID=<username>;PWD=<password>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new().set_username_and_password("user", "password");
+    /// ```
+    #[must_use]
+    pub fn set_username_and_password(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Sets/Replaces the host and removes the port (if it has been previously set)
+    ///
+    /// Parameters: `Server=<host>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new().set_host_with_default_port("localhost");
+    /// ```
+    #[must_use]
+    pub fn set_host_with_default_port(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self.port = None;
+        self
+    }
+
+    /// Sets/Replaces the host and the port
+    ///
+    /// Parameters: `Server=<host>;Port=<port>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new().set_host_with_port("localhost", 5000);
+    /// ```
+    #[must_use]
+    pub fn set_host_with_port(mut self, host: &str, port: usize) -> Self {
+        self.host = Some(host.to_string());
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets/Replaces the database name
+    ///
+    /// Parameters: `Database=<db_name>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// SybaseConnectionString::new().set_database_name("db_name");
+    /// ```
+    #[must_use]
+    pub fn set_database_name(mut self, db_name: &str) -> Self {
+        self.database = Some(db_name.to_string());
+        self
+    }
+
+    /// Renders the JDBC (jConnect) form of the connection string:
+    /// `jdbc:sybase:Tds:<host>:<port>/<db_name>`
+    ///
+    /// If no port has been set, the default port `5000` is used.
+    /// The DSN and the credentials aren't part of the JDBC URL and have to be passed to the driver separately.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use connection_string_generator::sybase::SybaseConnectionString;
+    ///
+    /// let conn_string = SybaseConnectionString::new()
+    ///     .set_host_with_default_port("localhost")
+    ///     .set_database_name("db_name");
+    ///
+    /// assert_eq!(conn_string.to_jdbc_string(), "jdbc:sybase:Tds:localhost:5000/db_name");
+    /// ```
+    #[must_use]
+    pub fn to_jdbc_string(&self) -> String {
+        let mut conn_string = String::from("jdbc:sybase:Tds:");
+
+        if let Some(host) = &self.host {
+            let port = self.port.unwrap_or(DEFAULT_PORT);
+            let _ = write!(conn_string, "{host}:{port}");
+        }
+
+        if let Some(database) = &self.database {
+            conn_string.push('/');
+            conn_string.push_str(database);
+        }
+
+        conn_string
+    }
+}
+
+impl Display for SybaseConnectionString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parameters = [
+            ("DSN", self.dsn.clone()),
+            ("Server", self.host.clone()),
+            ("Port", self.port.map(|port| port.to_string())),
+            ("Database", self.database.clone()),
+            ("UID", self.username.clone()),
+            ("PWD", self.password.clone()),
+        ];
+
+        let conn_string = parameters
+            .iter()
+            .filter_map(|(key, value)| {
+                value
+                    .as_ref()
+                    .map(|value| format!("{key}={}", odbc_encode(value)))
+            })
+            .collect::<Vec<String>>()
+            .join(";");
+
+        write!(f, "{conn_string}")
+    }
+}
+
+/// Simple encoding for values in an ODBC connection string
+///
+/// Values containing `;`, `{`, `}` or leading/trailing white space are enclosed in curly braces.
+/// Closing braces inside an enclosed value are escaped by doubling them (`}}`).
+fn odbc_encode(s: &str) -> String {
+    let braces_needed = s.starts_with(' ')
+        || s.ends_with(' ')
+        || s.contains(';')
+        || s.contains('{')
+        || s.contains('}');
+
+    if !braces_needed {
+        return s.to_string();
+    }
+
+    format!("{{{}}}", s.replace('}', "}}"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sybase::odbc_encode;
+
+    use super::SybaseConnectionString;
+
+    /// Test functionality of [`odbc_encode`]
+    #[test]
+    fn test_odbc_encode() {
+        assert_eq!(&odbc_encode("a"), "a");
+        assert_eq!(&odbc_encode("a a"), "a a");
+        assert_eq!(&odbc_encode(" a"), "{ a}");
+        assert_eq!(&odbc_encode("a;a"), "{a;a}");
+        assert_eq!(&odbc_encode("a}a"), "{a}}a}");
+    }
+
+    /// Test empty/default config
+    #[test]
+    fn test_empty() {
+        let conn_string = SybaseConnectionString::new();
+        assert_eq!(&conn_string.to_string(), "");
+        assert_eq!(&conn_string.to_jdbc_string(), "jdbc:sybase:Tds:");
+    }
+
+    /// Test setting username (and password)
+    #[test]
+    fn test_set_username() {
+        let conn_string = SybaseConnectionString::new().set_username_and_password("User", "Pwd");
+        assert_eq!(&conn_string.to_string(), "UID=User;PWD=Pwd");
+
+        // Replace username and implicitly delete password
+        let conn_string = conn_string.set_username_without_password("User2");
+        assert_eq!(&conn_string.to_string(), "UID=User2");
+    }
+
+    /// Test setting host config (host, host&port)
+    #[test]
+    fn test_set_host() {
+        let conn_string = SybaseConnectionString::new().set_host_with_port("Host", 5001);
+        assert_eq!(&conn_string.to_string(), "Server=Host;Port=5001");
+
+        let conn_string = conn_string.set_host_with_default_port("Host2");
+        assert_eq!(&conn_string.to_string(), "Server=Host2");
+    }
+
+    /// Test the ODBC keyword form with everything together
+    #[test]
+    fn test_all_together() {
+        let conn_string = SybaseConnectionString::new()
+            .set_dsn("dsn")
+            .set_username_and_password("user", "pass;word")
+            .set_host_with_port("localhost", 5000)
+            .set_database_name("db_name");
+
+        assert_eq!(
+            &conn_string.to_string(),
+            "DSN=dsn;Server=localhost;Port=5000;Database=db_name;UID=user;PWD={pass;word}"
+        );
+    }
+
+    /// Test the JDBC form
+    #[test]
+    fn test_to_jdbc_string() {
+        let conn_string = SybaseConnectionString::new()
+            .set_username_and_password("user", "password")
+            .set_host_with_port("localhost", 5001)
+            .set_database_name("db_name");
+        assert_eq!(
+            &conn_string.to_jdbc_string(),
+            "jdbc:sybase:Tds:localhost:5001/db_name"
+        );
+
+        let conn_string = conn_string.set_host_with_default_port("localhost");
+        assert_eq!(
+            &conn_string.to_jdbc_string(),
+            "jdbc:sybase:Tds:localhost:5000/db_name"
+        );
+    }
+}